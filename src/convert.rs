@@ -0,0 +1,475 @@
+//! Converts SMBIOS tables between the dump formats in common use.
+//!
+//! Supported formats are described by [TableFormat]. Conversions which
+//! would lose information, such as dropping the SMBIOS version when
+//! writing a raw table, are refused unless forced.
+use crate::core::{SMBiosData, SMBiosEntryPoint32, SMBiosEntryPoint64, SMBiosVersion};
+use crate::windows::WinSMBiosData;
+use std::{
+    convert::TryFrom,
+    fmt,
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+
+/// # SMBIOS Table Dump Format
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TableFormat {
+    /// Table data only, as found in /sys/firmware/dmi/tables/DMI
+    ///
+    /// This format does not record the SMBIOS version.
+    Raw,
+    /// Windows `RawSMBiosData` ([WinSMBiosData]): an 8 byte header
+    /// holding the version and length followed by the table data
+    Windows,
+    /// `dmidecode --dump-bin`: an entry point structure at offset 0
+    /// followed by the table data at offset 20h
+    Dmidecode,
+    /// JSON as produced by `smbiosdump dump -j`
+    ///
+    /// JSON holds decoded values and can only be written, not read.
+    Json,
+}
+
+impl TableFormat {
+    /// All supported formats
+    pub const ALL: [TableFormat; 4] = [
+        TableFormat::Raw,
+        TableFormat::Windows,
+        TableFormat::Dmidecode,
+        TableFormat::Json,
+    ];
+
+    /// Guesses the format of a dump from its content
+    ///
+    /// Data which is not recognized as any other format is assumed to be [TableFormat::Raw].
+    pub fn detect(data: &[u8]) -> TableFormat {
+        if data.starts_with(&SMBiosEntryPoint64::SM3_ANCHOR)
+            || data.starts_with(&SMBiosEntryPoint32::SM_ANCHOR)
+        {
+            TableFormat::Dmidecode
+        } else if data.iter().find(|byte| !byte.is_ascii_whitespace()) == Some(&b'{') {
+            TableFormat::Json
+        } else if WinSMBiosData::is_valid_win_smbios_data(&data.to_vec()) {
+            TableFormat::Windows
+        } else {
+            TableFormat::Raw
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            TableFormat::Raw => "raw",
+            TableFormat::Windows => "windows",
+            TableFormat::Dmidecode => "dmidecode",
+            TableFormat::Json => "json",
+        }
+    }
+}
+
+impl fmt::Display for TableFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for TableFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TableFormat::ALL
+            .iter()
+            .find(|format| format.name().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("unknown table format '{}'", s),
+                )
+            })
+    }
+}
+
+/// Offset of the table data in a `dmidecode --dump-bin` file
+const DMIDECODE_TABLE_OFFSET: usize = 0x20;
+
+/// Reads a table stored in the given format
+pub fn read_table(data: Vec<u8>, format: TableFormat) -> Result<SMBiosData, Error> {
+    match format {
+        TableFormat::Raw => Ok(SMBiosData::from_vec_and_version(data, None)),
+        TableFormat::Windows => Ok(WinSMBiosData::new(data)?.smbios_data),
+        TableFormat::Dmidecode => read_dmidecode(&data),
+        TableFormat::Json => Err(Error::new(
+            ErrorKind::InvalidInput,
+            "JSON holds decoded values and cannot be converted back to a binary table",
+        )),
+    }
+}
+
+/// Writes a table in the given format
+///
+/// Returns an error of kind [ErrorKind::InvalidInput] if the conversion
+/// would lose information, unless `force` is `true`.
+///
+/// [TableFormat::Windows] is written with a Used20CallingMethod of 0, use
+/// [convert_table] to keep the value of a Windows dump.
+pub fn write_table(data: &SMBiosData, format: TableFormat, force: bool) -> Result<Vec<u8>, Error> {
+    write_table_with_calling_method(data, format, 0, force)
+}
+
+fn write_table_with_calling_method(
+    data: &SMBiosData,
+    format: TableFormat,
+    used20_calling_method: u8,
+    force: bool,
+) -> Result<Vec<u8>, Error> {
+    if let Some(loss) = conversion_loss(data, format) {
+        refuse_loss(loss, force)?;
+    }
+
+    match format {
+        TableFormat::Raw => Ok(data.to_bytes()),
+        TableFormat::Windows => Ok(write_windows(data, used20_calling_method)),
+        TableFormat::Dmidecode => write_dmidecode(data),
+        TableFormat::Json => serde_json::to_vec(data).map_err(Error::from),
+    }
+}
+
+/// Converts a dump from one format to another
+///
+/// When `from` is `None` the input format is detected with [TableFormat::detect].
+///
+/// The Used20CallingMethod field of a Windows dump is kept when converting
+/// to [TableFormat::Windows]; a non-zero value is lost in any other format.
+pub fn convert_table(
+    input: Vec<u8>,
+    from: Option<TableFormat>,
+    to: TableFormat,
+    force: bool,
+) -> Result<Vec<u8>, Error> {
+    let from = from.unwrap_or_else(|| TableFormat::detect(&input));
+    let (data, used20_calling_method) = match from {
+        TableFormat::Windows => {
+            let windows = WinSMBiosData::new(input)?;
+            let used20_calling_method = windows.used20_calling_method();
+            (windows.smbios_data, used20_calling_method)
+        }
+        _ => (read_table(input, from)?, 0),
+    };
+
+    if used20_calling_method != 0 && to != TableFormat::Windows {
+        refuse_loss(
+            format!(
+                "{} format does not record the Used20CallingMethod ({:#04X})",
+                to, used20_calling_method
+            ),
+            force,
+        )?;
+    }
+    write_table_with_calling_method(&data, to, used20_calling_method, force)
+}
+
+/// Returns an error describing `loss` unless `force` is `true`
+fn refuse_loss(loss: String, force: bool) -> Result<(), Error> {
+    if force {
+        Ok(())
+    } else {
+        Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("{}; force the conversion to accept this", loss),
+        ))
+    }
+}
+
+/// Describes the information lost when writing `data` in `format`, if any
+fn conversion_loss(data: &SMBiosData, format: TableFormat) -> Option<String> {
+    match (format, data.version) {
+        (TableFormat::Raw, Some(version)) => Some(format!(
+            "raw tables do not record the SMBIOS version ({})",
+            fmt_version(&version)
+        )),
+        (TableFormat::Windows, None) | (TableFormat::Dmidecode, None) => Some(format!(
+            "the SMBIOS version is unknown and would be written as 0.0.0 in {} format",
+            format
+        )),
+        (TableFormat::Dmidecode, Some(version)) if version.major < 3 && version.revision != 0 => {
+            Some(format!(
+                "the SMBIOS 2.x entry point does not record the revision of version {}",
+                fmt_version(&version)
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn fmt_version(version: &SMBiosVersion) -> String {
+    format!("{}.{}.{}", version.major, version.minor, version.revision)
+}
+
+fn read_dmidecode(data: &[u8]) -> Result<SMBiosData, Error> {
+    let (version, table_address, table_length) =
+        if data.starts_with(&SMBiosEntryPoint64::SM3_ANCHOR) {
+            let entry_point = SMBiosEntryPoint64::try_from(entry_point_bytes(
+                data,
+                SMBiosEntryPoint64::ENTRY_POINT_LENGTH_OFFSET,
+            )?)?;
+            (
+                SMBiosVersion::new(
+                    entry_point.major_version(),
+                    entry_point.minor_version(),
+                    entry_point.docrev(),
+                ),
+                entry_point.structure_table_address() as usize,
+                entry_point.structure_table_maximum_size() as usize,
+            )
+        } else {
+            let entry_point = SMBiosEntryPoint32::try_from(entry_point_bytes(
+                data,
+                SMBiosEntryPoint32::ENTRY_POINT_LENGTH_OFFSET,
+            )?)?;
+            (
+                SMBiosVersion::new(entry_point.major_version(), entry_point.minor_version(), 0),
+                entry_point.structure_table_address() as usize,
+                entry_point.structure_table_length() as usize,
+            )
+        };
+
+    // The 3.0 entry point gives a maximum size, so the table may be shorter
+    let table = data
+        .get(table_address..)
+        .map(|table| &table[..table.len().min(table_length)])
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidData,
+                "The entry point table address is beyond the end of the data",
+            )
+        })?;
+
+    Ok(SMBiosData::from_vec_and_version(
+        table.to_vec(),
+        Some(version),
+    ))
+}
+
+/// Returns the entry point structure at the start of `data`, sized by its length field
+fn entry_point_bytes(data: &[u8], length_offset: usize) -> Result<Vec<u8>, Error> {
+    data.get(length_offset)
+        .and_then(|&length| data.get(..length as usize))
+        .map(|bytes| bytes.to_vec())
+        .ok_or_else(|| Error::new(ErrorKind::InvalidData, "Truncated entry point structure"))
+}
+
+fn write_windows(data: &SMBiosData, used20_calling_method: u8) -> Vec<u8> {
+    let version = data.version.unwrap_or_else(|| SMBiosVersion::new(0, 0, 0));
+    let table = data.to_bytes();
+
+    let mut result = vec![0u8; WinSMBiosData::SMBIOS_TABLE_DATA_OFFSET];
+    result[WinSMBiosData::USED20_CALLING_METHOD_OFFSET] = used20_calling_method;
+    result[WinSMBiosData::SMBIOS_MAJOR_VERSION_OFFSET] = version.major;
+    result[WinSMBiosData::SMBIOS_MINOR_VERSION_OFFSET] = version.minor;
+    result[WinSMBiosData::DMI_REVISION_OFFSET] = version.revision;
+    result[WinSMBiosData::TABLE_DATA_LENGTH_OFFSET..WinSMBiosData::SMBIOS_TABLE_DATA_OFFSET]
+        .copy_from_slice(&(table.len() as u32).to_le_bytes());
+    result.extend(table);
+    result
+}
+
+fn write_dmidecode(data: &SMBiosData) -> Result<Vec<u8>, Error> {
+    let version = data.version.unwrap_or_else(|| SMBiosVersion::new(0, 0, 0));
+    let table = data.to_bytes();
+
+    let mut result = vec![0u8; DMIDECODE_TABLE_OFFSET];
+    if version.major >= 3 {
+        write_entry_point_64(&mut result, &version, table.len())?;
+    } else {
+        write_entry_point_32(&mut result, data, &version, table.len())?;
+    }
+    result.extend(table);
+    Ok(result)
+}
+
+fn write_entry_point_64(
+    result: &mut [u8],
+    version: &SMBiosVersion,
+    table_length: usize,
+) -> Result<(), Error> {
+    let table_length = u32::try_from(table_length).map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidData,
+            "The table is too large for an SMBIOS 3.0 entry point",
+        )
+    })?;
+
+    let entry_point = &mut result[..SMBiosEntryPoint64::MINIMUM_SIZE];
+    entry_point[..SMBiosEntryPoint64::SM3_ANCHOR.len()]
+        .copy_from_slice(&SMBiosEntryPoint64::SM3_ANCHOR);
+    entry_point[SMBiosEntryPoint64::ENTRY_POINT_LENGTH_OFFSET] =
+        SMBiosEntryPoint64::MINIMUM_SIZE as u8;
+    entry_point[SMBiosEntryPoint64::MAJOR_VERSION_OFFSET] = version.major;
+    entry_point[SMBiosEntryPoint64::MINOR_VERSION_OFFSET] = version.minor;
+    entry_point[SMBiosEntryPoint64::DOCREV_OFFSET] = version.revision;
+    entry_point[SMBiosEntryPoint64::ENTRY_POINT_REVISION_OFFSET] = 0x01;
+    entry_point[SMBiosEntryPoint64::STRUCTURE_TABLE_MAXIMUM_SIZE_OFFSET
+        ..SMBiosEntryPoint64::STRUCTURE_TABLE_MAXIMUM_SIZE_OFFSET + 4]
+        .copy_from_slice(&table_length.to_le_bytes());
+    entry_point[SMBiosEntryPoint64::STRUCTURE_TABLE_ADDRESS_OFFSET
+        ..SMBiosEntryPoint64::STRUCTURE_TABLE_ADDRESS_OFFSET + 8]
+        .copy_from_slice(&(DMIDECODE_TABLE_OFFSET as u64).to_le_bytes());
+    entry_point[SMBiosEntryPoint64::ENTRY_POINT_STRUCTURE_CHECKSUM_OFFSET] = checksum(entry_point);
+    Ok(())
+}
+
+fn write_entry_point_32(
+    result: &mut [u8],
+    data: &SMBiosData,
+    version: &SMBiosVersion,
+    table_length: usize,
+) -> Result<(), Error> {
+    let too_large = || {
+        Error::new(
+            ErrorKind::InvalidData,
+            "The table is too large for an SMBIOS 2.1 entry point",
+        )
+    };
+    let table_length = u16::try_from(table_length).map_err(|_| too_large())?;
+    let number_of_structures = u16::try_from(data.iter().count()).map_err(|_| too_large())?;
+    let maximum_structure_size = data
+        .iter()
+        .map(|undefined_struct| undefined_struct.to_bytes().len())
+        .max()
+        .unwrap_or(0);
+    let maximum_structure_size = u16::try_from(maximum_structure_size).map_err(|_| too_large())?;
+    // The BCD revision can only express single digit versions
+    let bcd_revision = if version.major < 10 && version.minor < 10 {
+        version.major << 4 | version.minor
+    } else {
+        0
+    };
+
+    let entry_point = &mut result[..SMBiosEntryPoint32::MINIMUM_SIZE];
+    entry_point[..SMBiosEntryPoint32::SM_ANCHOR.len()]
+        .copy_from_slice(&SMBiosEntryPoint32::SM_ANCHOR);
+    entry_point[SMBiosEntryPoint32::ENTRY_POINT_LENGTH_OFFSET] =
+        SMBiosEntryPoint32::MINIMUM_SIZE as u8;
+    entry_point[SMBiosEntryPoint32::MAJOR_VERSION_OFFSET] = version.major;
+    entry_point[SMBiosEntryPoint32::MINOR_VERSION_OFFSET] = version.minor;
+    entry_point[SMBiosEntryPoint32::MAXIMUM_STRUCTURE_SIZE_OFFSET
+        ..SMBiosEntryPoint32::MAXIMUM_STRUCTURE_SIZE_OFFSET + 2]
+        .copy_from_slice(&maximum_structure_size.to_le_bytes());
+    entry_point[SMBiosEntryPoint32::INTERMEDIATE_ANCHOR_OFFSET
+        ..SMBiosEntryPoint32::INTERMEDIATE_ANCHOR_OFFSET + SMBiosEntryPoint32::DMI_ANCHOR.len()]
+        .copy_from_slice(&SMBiosEntryPoint32::DMI_ANCHOR);
+    entry_point[SMBiosEntryPoint32::STRUCTURE_TABLE_LENGTH_OFFSET
+        ..SMBiosEntryPoint32::STRUCTURE_TABLE_LENGTH_OFFSET + 2]
+        .copy_from_slice(&table_length.to_le_bytes());
+    entry_point[SMBiosEntryPoint32::STRUCTURE_TABLE_ADDRESS_OFFSET
+        ..SMBiosEntryPoint32::STRUCTURE_TABLE_ADDRESS_OFFSET + 4]
+        .copy_from_slice(&(DMIDECODE_TABLE_OFFSET as u32).to_le_bytes());
+    entry_point[SMBiosEntryPoint32::NUMBER_OF_SMBIOS_STRUCTURES_OFFSET
+        ..SMBiosEntryPoint32::NUMBER_OF_SMBIOS_STRUCTURES_OFFSET + 2]
+        .copy_from_slice(&number_of_structures.to_le_bytes());
+    entry_point[SMBiosEntryPoint32::BCD_REVISION_OFFSET] = bcd_revision;
+
+    // The intermediate checksum is covered by the entry point checksum so it goes first
+    entry_point[SMBiosEntryPoint32::INTERMEDIATE_CHECKSUM_OFFSET] =
+        checksum(&entry_point[SMBiosEntryPoint32::INTERMEDIATE_ANCHOR_OFFSET..]);
+    entry_point[SMBiosEntryPoint32::ENTRY_POINT_STRUCTURE_CHECKSUM_OFFSET] = checksum(entry_point);
+    Ok(())
+}
+
+/// Returns the byte which makes `data` sum to zero, given its checksum byte is currently zero
+fn checksum(data: &[u8]) -> u8 {
+    data.iter()
+        .fold(0u8, |sum, byte| sum.wrapping_add(*byte))
+        .wrapping_neg()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs::read, path::PathBuf};
+
+    fn windows_test_data() -> Vec<u8> {
+        let mut path = PathBuf::new();
+        path.push(".");
+        path.push("tests");
+        path.push("jeffgerlap_3_2_0");
+        path.set_extension("dat");
+        read(&path).unwrap()
+    }
+
+    #[test]
+    fn test_windows_dmidecode_round_trip() {
+        let windows = windows_test_data();
+        assert_eq!(TableFormat::detect(&windows), TableFormat::Windows);
+
+        let dmidecode =
+            convert_table(windows.clone(), None, TableFormat::Dmidecode, false).unwrap();
+        assert_eq!(TableFormat::detect(&dmidecode), TableFormat::Dmidecode);
+        assert_eq!(&dmidecode[..5], b"_SM3_");
+
+        let data = read_table(dmidecode.clone(), TableFormat::Dmidecode).unwrap();
+        assert_eq!(data.version, Some(SMBiosVersion::new(3, 2, 0)));
+        assert_eq!(data.iter().count(), 20);
+
+        let round_trip = convert_table(dmidecode, None, TableFormat::Windows, false).unwrap();
+        assert_eq!(round_trip, windows);
+    }
+
+    #[test]
+    fn test_entry_point_32() {
+        let mut data = read_table(windows_test_data(), TableFormat::Windows).unwrap();
+        data.version = Some(SMBiosVersion::new(2, 8, 0));
+
+        let dmidecode = write_table(&data, TableFormat::Dmidecode, false).unwrap();
+        let entry_point =
+            SMBiosEntryPoint32::try_from(dmidecode[..SMBiosEntryPoint32::MINIMUM_SIZE].to_vec())
+                .unwrap();
+        assert_eq!(entry_point.bcd_revision(), 0x28);
+        assert_eq!(entry_point.number_of_smbios_structures(), 20);
+        assert_eq!(
+            entry_point.structure_table_length() as usize,
+            dmidecode.len() - DMIDECODE_TABLE_OFFSET
+        );
+
+        let read_back = read_table(dmidecode, TableFormat::Dmidecode).unwrap();
+        assert_eq!(read_back.version, data.version);
+        assert_eq!(read_back.to_bytes(), data.to_bytes());
+    }
+
+    #[test]
+    fn test_lossy_conversion_refused() {
+        let windows = windows_test_data();
+
+        let error = convert_table(windows.clone(), None, TableFormat::Raw, false).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+
+        let raw = convert_table(windows.clone(), None, TableFormat::Raw, true).unwrap();
+        assert_eq!(
+            raw,
+            windows[WinSMBiosData::SMBIOS_TABLE_DATA_OFFSET..].to_vec()
+        );
+        assert_eq!(TableFormat::detect(&raw), TableFormat::Raw);
+
+        let error = convert_table(raw, None, TableFormat::Windows, false).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+
+        let json = convert_table(windows, None, TableFormat::Json, false).unwrap();
+        assert_eq!(TableFormat::detect(&json), TableFormat::Json);
+        assert!(read_table(json, TableFormat::Json).is_err());
+    }
+
+    #[test]
+    fn test_used20_calling_method() {
+        let mut windows = windows_test_data();
+        windows[WinSMBiosData::USED20_CALLING_METHOD_OFFSET] = 0x01;
+
+        let round_trip = convert_table(windows.clone(), None, TableFormat::Windows, false).unwrap();
+        assert_eq!(round_trip, windows);
+
+        let error =
+            convert_table(windows.clone(), None, TableFormat::Dmidecode, false).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert!(convert_table(windows, None, TableFormat::Dmidecode, true).is_ok());
+    }
+}
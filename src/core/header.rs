@@ -7,7 +7,7 @@ use std::{convert::TryInto, fmt, ops::Deref, str::FromStr};
 /// Some structures will reference other structures by using this value.
 ///
 /// Dereference a handle (*handle) to access its u16 value.
-#[derive(Serialize, PartialEq, Eq)]
+#[derive(Serialize, PartialEq, Eq, Hash, Clone, Copy)]
 pub struct Handle(pub u16);
 
 impl Handle {
@@ -3,7 +3,10 @@ use super::undefined_struct::{UndefinedStruct, UndefinedStructTable};
 use crate::structs::{DefinedStructTable, SMBiosStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::io::Error;
-use std::{cmp::Ordering, slice::Iter};
+use std::{
+    cmp::Ordering,
+    slice::{Iter, IterMut},
+};
 use std::{fmt, fs::read};
 
 /// # SMBIOS Data
@@ -53,6 +56,22 @@ impl<'a> SMBiosData {
         self.table.iter()
     }
 
+    /// Mutable iterator of the contained [UndefinedStruct] items
+    pub fn iter_mut(&mut self) -> IterMut<'_, UndefinedStruct> {
+        self.table.iter_mut()
+    }
+
+    /// Converts the contained structures back into raw table data
+    ///
+    /// The result has the same layout as the data returned by `raw_smbios_from_device()`
+    /// on Linux and can be written with [crate::dump_raw].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.table
+            .iter()
+            .flat_map(|undefined_struct| undefined_struct.to_bytes())
+            .collect()
+    }
+
     /// An iterator over the defined type instances within the table.
     pub fn defined_struct_iter<T: 'a>(&'a self) -> impl Iterator<Item = T> + 'a
     where
@@ -15,7 +15,7 @@ impl SMBiosStringSet {
     pub fn new(string_area: Vec<u8>) -> SMBiosStringSet {
         SMBiosStringSet {
             strings: {
-                if string_area == &[] {
+                if string_area.is_empty() {
                     vec![]
                 } else {
                     string_area
@@ -72,6 +72,14 @@ impl SMBiosStringSet {
     pub fn iter(&self) -> std::slice::Iter<'_, Vec<u8>> {
         self.strings.iter()
     }
+
+    /// Iterates the raw bytes of the strings with their 1 based string number.
+    ///
+    /// String numbers are a byte, so strings past the 255th, which no field
+    /// can reference, are not included.
+    pub fn iter_numbered(&self) -> impl Iterator<Item = (u8, &Vec<u8>)> + '_ {
+        (1..=u8::MAX).zip(self.strings.iter())
+    }
 }
 
 impl Iterator for SMBiosStringSet {
@@ -326,4 +334,20 @@ mod tests {
         let fourth_string = string_iterator.next().unwrap().value.unwrap();
         assert_eq!(fourth_string, "ja|JP|unicode".to_string());
     }
+
+    #[test]
+    fn test_iter_numbered() {
+        let string_set = SMBiosStringSet::new(b"first\0second".to_vec());
+        let numbered: Vec<(u8, &Vec<u8>)> = string_set.iter_numbered().collect();
+        assert_eq!(
+            numbered,
+            vec![(1, &b"first".to_vec()), (2, &b"second".to_vec())]
+        );
+
+        // Strings past the 255th have no string number
+        let string_set = SMBiosStringSet::new(b"enUS\0".repeat(300)[..1499].to_vec());
+        assert_eq!(string_set.iter().count(), 300);
+        assert_eq!(string_set.iter_numbered().count(), 255);
+        assert_eq!(string_set.iter_numbered().last().unwrap().0, 255);
+    }
 }
@@ -7,7 +7,7 @@ use std::{
     convert::TryInto,
     fs::File,
     io::{prelude::*, Error, ErrorKind, SeekFrom},
-    slice::Iter,
+    slice::{Iter, IterMut},
 };
 /// # Embodies the three basic parts of an SMBIOS structure
 ///
@@ -166,6 +166,24 @@ impl<'a> UndefinedStruct {
     pub fn defined_struct(&self) -> DefinedStruct<'_> {
         self.into()
     }
+
+    /// Converts the structure back into its raw form
+    ///
+    /// The result contains the header and fields followed by the
+    /// string-set and its double zero terminator, as found in the table.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut raw = self.fields.clone();
+        if self.strings.iter().len() == 0 {
+            raw.push(0);
+        } else {
+            for string in self.strings.iter() {
+                raw.extend_from_slice(string);
+                raw.push(0);
+            }
+        }
+        raw.push(0);
+        raw
+    }
 }
 
 impl fmt::Debug for UndefinedStruct {
@@ -210,6 +228,11 @@ impl<'a> UndefinedStructTable {
         self.0.iter()
     }
 
+    /// Mutable iterator of the contained [UndefinedStruct] items.
+    pub fn iter_mut(&mut self) -> IterMut<'_, UndefinedStruct> {
+        self.0.iter_mut()
+    }
+
     /// An iterator over the defined type instances within the table.
     pub fn defined_struct_iter<T>(&'a self) -> impl Iterator<Item = T> + 'a
     where
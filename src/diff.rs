@@ -0,0 +1,409 @@
+//! Compares two SMBIOS tables.
+//!
+//! Useful for finding out what changed after a BIOS update or a hardware
+//! swap. Structures are paired by handle first, and the remaining ones by
+//! structure type plus a stable identity such as a socket designation or
+//! device locator. Paired structures are compared using the decoded fields
+//! of their [DefinedStruct](crate::DefinedStruct) rather than their raw bytes.
+use crate::core::{Handle, SMBiosData, SMBiosVersion, UndefinedStruct};
+use crate::struct_identity::struct_identity;
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+
+/// # SMBIOS Table Difference
+///
+/// The result of comparing an _old_ table against a _new_ table.
+#[derive(Debug, Serialize)]
+pub struct SMBiosDiff {
+    /// SMBIOS version of the old table
+    pub old_version: Option<SMBiosVersion>,
+    /// SMBIOS version of the new table
+    pub new_version: Option<SMBiosVersion>,
+    /// Structures found only in the new table
+    pub added: Vec<StructSummary>,
+    /// Structures found only in the old table
+    pub removed: Vec<StructSummary>,
+    /// Structures found in both tables with at least one differing field
+    pub changed: Vec<StructChange>,
+}
+
+/// # Structure Summary
+///
+/// Identifies a structure which was added or removed.
+#[derive(Debug, Serialize)]
+pub struct StructSummary {
+    /// The structure type
+    pub struct_type: u8,
+    /// The structure handle
+    pub handle: Handle,
+    /// The locator or designation string identifying the structure, if it has one
+    pub identity: Option<String>,
+}
+
+/// # Structure Change
+///
+/// A structure present in both tables along with its differing fields.
+#[derive(Debug, Serialize)]
+pub struct StructChange {
+    /// The structure type
+    pub struct_type: u8,
+    /// The structure handle in the old table
+    pub old_handle: Handle,
+    /// The structure handle in the new table
+    pub new_handle: Handle,
+    /// The locator or designation string identifying the structure, if it has one
+    pub identity: Option<String>,
+    /// The fields which differ
+    pub fields: Vec<FieldChange>,
+}
+
+/// # Field Change
+///
+/// A single decoded field whose value differs between the two tables.
+///
+/// Nested values are flattened into a dotted path, for example
+/// `header.handle` or `processor_family.value`. A field which is absent
+/// on one side is represented as [Value::Null].
+#[derive(Debug, Serialize)]
+pub struct FieldChange {
+    /// Dotted path of the field
+    pub field: String,
+    /// Value in the old table
+    pub old: Value,
+    /// Value in the new table
+    pub new: Value,
+}
+
+impl SMBiosDiff {
+    /// Returns `true` if the two tables have no differences
+    pub fn is_empty(&self) -> bool {
+        self.old_version == self.new_version
+            && self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+    }
+}
+
+/// Compares two tables and returns their differences.
+///
+/// A structure in `old` is paired with a structure in `new` when:
+/// 1. Both have the same handle and type, otherwise
+/// 2. Both have the same type and the same identity (see [struct_identity]), otherwise
+/// 3. Both have the same type, no identity, and are the only unpaired structure
+///    of that type in their respective tables.
+pub fn diff(old: &SMBiosData, new: &SMBiosData) -> SMBiosDiff {
+    let old_structs: Vec<&UndefinedStruct> = old.iter().collect();
+    let new_structs: Vec<&UndefinedStruct> = new.iter().collect();
+    let mut new_paired = vec![false; new_structs.len()];
+    let mut pairs: Vec<(usize, usize)> = Vec::new();
+    let mut old_unpaired: Vec<usize> = Vec::new();
+
+    for (old_index, old_struct) in old_structs.iter().enumerate() {
+        match (0..new_structs.len()).find(|&new_index| {
+            !new_paired[new_index]
+                && new_structs[new_index].header.handle() == old_struct.header.handle()
+                && new_structs[new_index].header.struct_type() == old_struct.header.struct_type()
+        }) {
+            Some(new_index) => {
+                new_paired[new_index] = true;
+                pairs.push((old_index, new_index));
+            }
+            None => old_unpaired.push(old_index),
+        }
+    }
+
+    let mut removed_indexes: Vec<usize> = Vec::new();
+    for old_index in old_unpaired.iter().copied() {
+        let old_struct = old_structs[old_index];
+        let struct_type = old_struct.header.struct_type();
+        let candidate = match struct_identity(old_struct) {
+            Some(identity) => (0..new_structs.len()).find(|&new_index| {
+                !new_paired[new_index]
+                    && new_structs[new_index].header.struct_type() == struct_type
+                    && struct_identity(new_structs[new_index]).as_ref() == Some(&identity)
+            }),
+            None => {
+                let is_anonymous = |s: &UndefinedStruct| {
+                    s.header.struct_type() == struct_type && struct_identity(s).is_none()
+                };
+                let old_count = old_unpaired
+                    .iter()
+                    .filter(|&&index| is_anonymous(old_structs[index]))
+                    .count();
+                let new_candidates: Vec<usize> = (0..new_structs.len())
+                    .filter(|&index| !new_paired[index] && is_anonymous(new_structs[index]))
+                    .collect();
+                match (old_count, new_candidates.as_slice()) {
+                    (1, [new_index]) => Some(*new_index),
+                    _ => None,
+                }
+            }
+        };
+
+        match candidate {
+            Some(new_index) => {
+                new_paired[new_index] = true;
+                pairs.push((old_index, new_index));
+            }
+            None => removed_indexes.push(old_index),
+        }
+    }
+
+    let changed = pairs
+        .into_iter()
+        .filter_map(|(old_index, new_index)| {
+            compare_structs(old_structs[old_index], new_structs[new_index])
+        })
+        .collect();
+
+    let removed = removed_indexes
+        .into_iter()
+        .map(|index| StructSummary::from(old_structs[index]))
+        .collect();
+
+    let added = new_structs
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| !new_paired[*index])
+        .map(|(_, s)| StructSummary::from(*s))
+        .collect();
+
+    SMBiosDiff {
+        old_version: old.version,
+        new_version: new.version,
+        added,
+        removed,
+        changed,
+    }
+}
+
+impl From<&UndefinedStruct> for StructSummary {
+    fn from(undefined_struct: &UndefinedStruct) -> Self {
+        StructSummary {
+            struct_type: undefined_struct.header.struct_type(),
+            handle: undefined_struct.header.handle(),
+            identity: struct_identity(undefined_struct),
+        }
+    }
+}
+
+fn compare_structs(old: &UndefinedStruct, new: &UndefinedStruct) -> Option<StructChange> {
+    let old_fields = decoded_fields(old);
+    let new_fields = decoded_fields(new);
+
+    let mut fields: Vec<FieldChange> = Vec::new();
+    for (name, old_value) in old_fields.iter() {
+        let new_value = new_fields
+            .iter()
+            .find(|(new_name, _)| new_name == name)
+            .map(|(_, value)| value.clone())
+            .unwrap_or(Value::Null);
+        if *old_value != new_value {
+            fields.push(FieldChange {
+                field: name.clone(),
+                old: old_value.clone(),
+                new: new_value,
+            });
+        }
+    }
+    for (name, new_value) in new_fields.iter() {
+        if !old_fields.iter().any(|(old_name, _)| old_name == name) {
+            fields.push(FieldChange {
+                field: name.clone(),
+                old: Value::Null,
+                new: new_value.clone(),
+            });
+        }
+    }
+
+    match fields.is_empty() {
+        true => None,
+        false => Some(StructChange {
+            struct_type: new.header.struct_type(),
+            old_handle: old.header.handle(),
+            new_handle: new.header.handle(),
+            identity: struct_identity(new),
+            fields,
+        }),
+    }
+}
+
+/// Serializes the defined structure and flattens it into (dotted path, value) pairs.
+fn decoded_fields(undefined_struct: &UndefinedStruct) -> Vec<(String, Value)> {
+    let mut result = Vec::new();
+    // A DefinedStruct serializes as { "VariantName": { fields } }
+    let value = match serde_json::to_value(undefined_struct.defined_struct()) {
+        Ok(Value::Object(variant)) => variant
+            .into_iter()
+            .next()
+            .map(|(_, fields)| fields)
+            .unwrap_or(Value::Null),
+        Ok(other) => other,
+        Err(_) => Value::Null,
+    };
+    flatten_value(String::new(), value, &mut result);
+    result
+}
+
+fn flatten_value(path: String, value: Value, result: &mut Vec<(String, Value)>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                let child_path = match path.is_empty() {
+                    true => key,
+                    false => format!("{}.{}", path, key),
+                };
+                flatten_value(child_path, child, result);
+            }
+        }
+        other => result.push((path, other)),
+    }
+}
+
+fn fmt_version(version: &Option<SMBiosVersion>) -> String {
+    match version {
+        Some(version) => format!("{}.{}.{}", version.major, version.minor, version.revision),
+        None => "unknown".to_string(),
+    }
+}
+
+impl fmt::Display for StructSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Type {} handle {:#06X}", self.struct_type, *self.handle)?;
+        match &self.identity {
+            Some(identity) => write!(f, " \"{}\"", identity),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for StructChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Type {} handle {:#06X}",
+            self.struct_type, *self.old_handle
+        )?;
+        if self.old_handle != self.new_handle {
+            write!(f, " -> {:#06X}", *self.new_handle)?;
+        }
+        if let Some(identity) = &self.identity {
+            write!(f, " \"{}\"", identity)?;
+        }
+        for field in self.fields.iter() {
+            write!(f, "\n    {}: {} -> {}", field.field, field.old, field.new)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for SMBiosDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No differences");
+        }
+
+        if self.old_version != self.new_version {
+            writeln!(
+                f,
+                "SMBIOS version: {} -> {}",
+                fmt_version(&self.old_version),
+                fmt_version(&self.new_version)
+            )?;
+        }
+        for summary in self.added.iter() {
+            writeln!(f, "+ {}", summary)?;
+        }
+        for summary in self.removed.iter() {
+            writeln!(f, "- {}", summary)?;
+        }
+        for change in self.changed.iter() {
+            writeln!(f, "~ {}", change)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type17(handle: u8, locator: &str, size_mb: u16) -> Vec<u8> {
+        let mut raw = vec![
+            0x11, 0x15, handle, 0x00, // header
+            0x01, 0x00, // physical memory array handle
+            0xFE, 0xFF, // memory error information handle
+            0x40, 0x00, // total width
+            0x40, 0x00, // data width
+        ];
+        raw.extend_from_slice(&size_mb.to_le_bytes());
+        raw.extend_from_slice(&[
+            0x0D, // form factor
+            0x00, // device set
+            0x01, // device locator
+            0x00, // bank locator
+            0x1A, // memory type
+        ]);
+        raw.extend_from_slice(&[0x80, 0x00]); // type detail
+        raw.extend_from_slice(locator.as_bytes());
+        raw.extend_from_slice(&[0x00, 0x00]);
+        raw
+    }
+
+    fn table(structs: &[Vec<u8>]) -> SMBiosData {
+        let mut raw: Vec<u8> = structs.concat();
+        raw.extend_from_slice(&[0x7F, 0x04, 0xFF, 0xFE, 0x00, 0x00]);
+        SMBiosData::from_vec_and_version(raw, Some(SMBiosVersion::new(3, 2, 0)))
+    }
+
+    #[test]
+    fn test_identical_tables() {
+        let old = table(&[type17(0x10, "DIMM 0", 8192)]);
+        let new = table(&[type17(0x10, "DIMM 0", 8192)]);
+        let result = diff(&old, &new);
+        assert!(result.is_empty());
+        assert_eq!(format!("{}", result), "No differences\n");
+    }
+
+    #[test]
+    fn test_changed_added_removed() {
+        let old = table(&[type17(0x10, "DIMM 0", 8192), type17(0x11, "DIMM 1", 8192)]);
+        // DIMM 0 grew, DIMM 1 was removed and DIMM 2 was added
+        let new = table(&[type17(0x10, "DIMM 0", 16384), type17(0x12, "DIMM 2", 8192)]);
+        let result = diff(&old, &new);
+
+        assert_eq!(result.changed.len(), 1);
+        let change = &result.changed[0];
+        assert_eq!(change.struct_type, 17);
+        assert_eq!(change.identity, Some("DIMM 0".to_string()));
+        assert_eq!(change.fields.len(), 1);
+        assert_eq!(change.fields[0].field, "size.Megabytes");
+        assert_eq!(change.fields[0].old, Value::from(8192));
+        assert_eq!(change.fields[0].new, Value::from(16384));
+
+        assert_eq!(result.removed.len(), 1);
+        assert_eq!(result.removed[0].handle, Handle(0x11));
+        assert_eq!(result.added.len(), 1);
+        assert_eq!(result.added[0].handle, Handle(0x12));
+        assert_eq!(result.added[0].identity, Some("DIMM 2".to_string()));
+    }
+
+    #[test]
+    fn test_renumbered_handle_matched_by_locator() {
+        let old = table(&[type17(0x10, "DIMM 0", 8192)]);
+        let new = table(&[type17(0x20, "DIMM 0", 8192)]);
+        let result = diff(&old, &new);
+
+        assert!(result.added.is_empty());
+        assert!(result.removed.is_empty());
+        assert_eq!(result.changed.len(), 1);
+        assert_eq!(result.changed[0].old_handle, Handle(0x10));
+        assert_eq!(result.changed[0].new_handle, Handle(0x20));
+        assert_eq!(result.changed[0].fields[0].field, "header.handle");
+        assert_eq!(
+            format!("{}", result),
+            "~ Type 17 handle 0x0010 -> 0x0020 \"DIMM 0\"\n    header.handle: 16 -> 32\n"
+        );
+    }
+}
@@ -0,0 +1,149 @@
+//! Renders the handle references of a table as a Graphviz DOT graph.
+//!
+//! Only structures which take part in a reference are drawn. Edges point
+//! from the containing structure to the contained one (processor to cache,
+//! memory array to memory device, power supply to probe) so that `dot`
+//! lays the topology out top-down; where the reference itself runs the
+//! other way the arrowhead is reversed with `dir=back`.
+use crate::core::{Handle, SMBiosData};
+use crate::handle_graph::{HandleEdge, HandleGraph};
+use crate::struct_identity::struct_identity;
+use std::{collections::BTreeSet, fmt};
+
+/// References from a structure to its parent, as (struct type, field)
+const PARENT_REFERENCES: [(u8, &str); 7] = [
+    (17, "physical_memory_array_handle"),
+    (19, "physical_memory_array_handle"),
+    (20, "memory_device_handle"),
+    (20, "memory_array_mapped_address_handle"),
+    (35, "management_device_handle"),
+    (44, "referenced_handle"),
+    (46, "parent_handle"),
+];
+
+/// # DOT Graph
+///
+/// Displays the structure topology of an [SMBiosData] table in the DOT language.
+///
+/// Example output:
+/// ```text
+/// digraph smbios {
+///     node [shape=box];
+///     "0x0003" [label="Type 7 CacheInformation\nL1 - Cache"];
+///     "0x0006" [label="Type 4 ProcessorInformation\nFP5"];
+///     "0x0006" -> "0x0003" [label="l1cache_handle"];
+/// }
+/// ```
+pub struct DotGraph<'a> {
+    data: &'a SMBiosData,
+    graph: HandleGraph,
+}
+
+impl SMBiosData {
+    /// Returns a displayable DOT graph of the handle references in the table
+    pub fn dot_graph(&self) -> DotGraph<'_> {
+        DotGraph {
+            data: self,
+            graph: HandleGraph::new(self),
+        }
+    }
+}
+
+impl DotGraph<'_> {
+    fn is_parent_reference(&self, edge: &HandleEdge) -> bool {
+        matches!(
+            self.graph.struct_type(edge.from),
+            Some(struct_type) if PARENT_REFERENCES.contains(&(struct_type, edge.field))
+        )
+    }
+
+    fn node_label(&self, handle: Handle) -> String {
+        let undefined_struct = match self.data.find_by_handle(&handle) {
+            Some(undefined_struct) => undefined_struct,
+            None => return "missing".to_string(),
+        };
+
+        let struct_type = undefined_struct.header.struct_type();
+        let mut label = format!(
+            "Type {} {}",
+            struct_type,
+            undefined_struct.defined_struct().type_name()
+        );
+        if let Some(identity) = struct_identity(undefined_struct) {
+            label.push_str("\\n");
+            label.push_str(&escape(&identity));
+        }
+        label
+    }
+}
+
+/// Escapes a string for use within a double quoted DOT string
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+impl fmt::Display for DotGraph<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "digraph smbios {{")?;
+        writeln!(f, "    node [shape=box];")?;
+
+        let nodes: BTreeSet<u16> = self
+            .graph
+            .edges()
+            .iter()
+            .flat_map(|edge| vec![*edge.from, *edge.to])
+            .collect();
+        for node in nodes {
+            writeln!(
+                f,
+                "    \"{:#06X}\" [label=\"{}\"];",
+                node,
+                self.node_label(Handle(node))
+            )?;
+        }
+
+        for edge in self.graph.edges() {
+            if self.is_parent_reference(edge) {
+                writeln!(
+                    f,
+                    "    \"{:#06X}\" -> \"{:#06X}\" [label=\"{}\", dir=back];",
+                    *edge.to, *edge.from, edge.field
+                )?;
+            } else {
+                writeln!(
+                    f,
+                    "    \"{:#06X}\" -> \"{:#06X}\" [label=\"{}\"];",
+                    *edge.from, *edge.to, edge.field
+                )?;
+            }
+        }
+
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_dot_graph() {
+        let mut path = PathBuf::new();
+        path.push(".");
+        path.push("tests");
+        path.push("jeffgerlap_3_2_0");
+        path.set_extension("dat");
+
+        let data = crate::load_smbios_data_from_file(&path).unwrap();
+        let dot = format!("{}", data.dot_graph());
+
+        assert!(dot.starts_with("digraph smbios {\n    node [shape=box];\n"));
+        assert!(dot.ends_with("}\n"));
+        assert!(dot.contains("    \"0x0006\" [label=\"Type 4 ProcessorInformation\\nFP5\"];\n"));
+        assert!(dot.contains("    \"0x0006\" -> \"0x0003\" [label=\"l1cache_handle\"];\n"));
+        // Memory devices refer to their array, drawn from the array down
+        assert!(dot.contains(
+            "    \"0x0001\" -> \"0x0008\" [label=\"physical_memory_array_handle\", dir=back];\n"
+        ));
+    }
+}
@@ -0,0 +1,659 @@
+//! System event log contents described by a System Event Log (Type 15) structure.
+//!
+//! [SMBiosSystemEventLog] tells where the log lives and how it is laid out;
+//! [EventLog] reads the log area itself, from physical memory or through the
+//! index and data ports of a [PortIo], and decodes its header and records.
+//! Log records hold a BCD timestamp and variable data whose standard
+//! format is given per event type by the structure's type descriptors.
+use crate::core::Handle;
+use crate::structs::*;
+use serde::Serialize;
+use std::{
+    collections::HashMap,
+    convert::{TryFrom, TryInto},
+    io::{Error, ErrorKind, Read, Seek, SeekFrom, Write},
+};
+
+/// Event type marking the end of the log records
+const END_OF_LOG: u8 = 0xFF;
+
+/// Length of the fixed part of a log record, from Event Type through Second
+const RECORD_HEADER_LENGTH: usize = 8;
+
+/// # Event Log
+///
+/// Header and records of a system event log area.
+#[derive(Serialize, Debug)]
+pub struct EventLog {
+    /// Log header, as given by the structure's header format
+    pub header: EventLogHeader,
+    /// Log records in the order they appear in the log area
+    pub records: Vec<EventLogRecord>,
+}
+
+impl EventLog {
+    /// Reads the log area of a memory-mapped event log from a physical memory source
+    ///
+    /// `memory` is addressed by physical address, such as `/dev/mem`.
+    /// Returns an error of kind [ErrorKind::Unsupported] for access methods
+    /// other than [AccessMethod::MemoryMapped32Bit].
+    pub fn read<R: Read + Seek>(
+        event_log: &SMBiosSystemEventLog<'_>,
+        memory: &mut R,
+    ) -> Result<Self, Error> {
+        let address = match event_log.decoded_access_method_address() {
+            Some(AccessMethodAddress::PhysicalAddress(address)) => address,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "event log access method is not memory-mapped",
+                ))
+            }
+        };
+        let (header_start, length) = log_area_bounds(event_log)?;
+
+        let mut log_area = vec![0u8; length as usize];
+        memory.seek(SeekFrom::Start(address as u64 + header_start as u64))?;
+        memory.read_exact(&mut log_area)?;
+        EventLog::from_log_area(event_log, &log_area)
+    }
+
+    /// Reads the log area of an indexed I/O event log through its index and data ports
+    ///
+    /// Each byte of the log area is read by writing its offset to the index
+    /// port(s) and reading the data port. Returns an error of kind
+    /// [ErrorKind::Unsupported] for access methods other than the indexed I/O methods.
+    pub fn read_indexed_io<P: PortIo>(
+        event_log: &SMBiosSystemEventLog<'_>,
+        ports: &mut P,
+    ) -> Result<Self, Error> {
+        let (index_port, data_port) = match event_log.decoded_access_method_address() {
+            Some(AccessMethodAddress::IndexedIO {
+                index_port,
+                data_port,
+            }) => (index_port, data_port),
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "event log access method is not indexed I/O",
+                ))
+            }
+        };
+        let access_method = event_log
+            .access_method()
+            .ok_or_else(|| invalid_data("event log access method is missing"))?;
+        let (header_start, length) = log_area_bounds(event_log)?;
+
+        let mut log_area = Vec::with_capacity(length as usize);
+        for index in header_start as u32..header_start as u32 + length as u32 {
+            match access_method.value {
+                AccessMethod::IndexedIO18Bit => {
+                    let index = u8::try_from(index).map_err(|_| {
+                        invalid_data("event log area exceeds the 8-bit index range")
+                    })?;
+                    ports.write_u8(index_port, index)?;
+                }
+                AccessMethod::IndexedIO28Bit => {
+                    let index = u16::try_from(index).map_err(|_| {
+                        invalid_data("event log area exceeds the 16-bit index range")
+                    })?;
+                    ports.write_u8(index_port, index as u8)?;
+                    ports.write_u8(index_port.wrapping_add(1), (index >> 8) as u8)?;
+                }
+                _ => {
+                    let index = u16::try_from(index).map_err(|_| {
+                        invalid_data("event log area exceeds the 16-bit index range")
+                    })?;
+                    ports.write_u16(index_port, index)?;
+                }
+            }
+            log_area.push(ports.read_u8(data_port)?);
+        }
+        EventLog::from_log_area(event_log, &log_area)
+    }
+
+    /// Decodes a captured log area
+    ///
+    /// `log_area` starts at the log header start offset and holds the
+    /// log area length bytes of the log.
+    pub fn from_log_area(
+        event_log: &SMBiosSystemEventLog<'_>,
+        log_area: &[u8],
+    ) -> Result<Self, Error> {
+        let header_start = event_log
+            .log_header_start_offset()
+            .ok_or_else(|| invalid_data("event log header start offset is missing"))?;
+        let data_start = event_log
+            .log_data_start_offset()
+            .ok_or_else(|| invalid_data("event log data start offset is missing"))?;
+        let header_length = data_start
+            .checked_sub(header_start)
+            .ok_or_else(|| invalid_data("event log data starts before its header"))?
+            as usize;
+        if header_length > log_area.len() {
+            return Err(invalid_data("event log header is larger than the log area"));
+        }
+        let (header, data) = log_area.split_at(header_length);
+
+        let header = match event_log.log_header_format().map(|format| format.value) {
+            Some(HeaderFormat::NoHeader) => EventLogHeader::NoHeader,
+            Some(HeaderFormat::Type1LogHeader) => EventLogHeaderType1::new(header)
+                .map(EventLogHeader::Type1)
+                .ok_or_else(|| invalid_data("event log type 1 header is too short"))?,
+            _ => EventLogHeader::Other(header.to_vec()),
+        };
+
+        let formats: HashMap<u8, u8> = event_log
+            .type_descriptors()
+            .map(|type_descriptors| {
+                type_descriptors
+                    .into_iter()
+                    .map(|descriptor| {
+                        (
+                            descriptor.log_type().raw,
+                            descriptor.variable_data_format_type().raw,
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut records = Vec::new();
+        let mut offset = 0;
+        while offset + RECORD_HEADER_LENGTH <= data.len() && data[offset] != END_OF_LOG {
+            let length = (data[offset + 1] & 0x7F) as usize;
+            if length < RECORD_HEADER_LENGTH || offset + length > data.len() {
+                break;
+            }
+            let record = &data[offset..offset + length];
+            records.push(EventLogRecord::new(
+                record,
+                formats.get(&record[0]).copied(),
+            ));
+            offset += length;
+        }
+
+        Ok(EventLog { header, records })
+    }
+}
+
+/// Log header start offset and log area length of an event log
+fn log_area_bounds(event_log: &SMBiosSystemEventLog<'_>) -> Result<(u16, u16), Error> {
+    let header_start = event_log
+        .log_header_start_offset()
+        .ok_or_else(|| invalid_data("event log header start offset is missing"))?;
+    let length = event_log
+        .log_area_length()
+        .ok_or_else(|| invalid_data("event log area length is missing"))?;
+    Ok((header_start, length))
+}
+
+fn invalid_data(message: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
+
+/// # Port I/O
+///
+/// Access to the I/O port space, used to read indexed I/O event logs.
+pub trait PortIo {
+    /// Reads a byte from an I/O port
+    fn read_u8(&mut self, port: u16) -> Result<u8, Error>;
+    /// Writes a byte to an I/O port
+    fn write_u8(&mut self, port: u16, value: u8) -> Result<(), Error>;
+    /// Writes a word to an I/O port with a single 16-bit access
+    ///
+    /// Implementations which can only access a port a byte at a time return
+    /// an error of kind [ErrorKind::Unsupported], as two byte writes are not
+    /// equivalent to a 16-bit write.
+    fn write_u16(&mut self, port: u16, value: u16) -> Result<(), Error>;
+}
+
+/// # Port File
+///
+/// [PortIo] over a file addressed by I/O port, such as `/dev/port`.
+///
+/// `/dev/port` accesses ports a byte at a time, so [PortIo::write_u16] is
+/// unsupported and logs using [AccessMethod::IndexedIO116Bit] cannot be read
+/// through a [PortFile].
+pub struct PortFile<T> {
+    file: T,
+}
+
+impl<T: Read + Write + Seek> PortFile<T> {
+    /// Creates port I/O over a file addressed by I/O port
+    pub fn new(file: T) -> Self {
+        PortFile { file }
+    }
+}
+
+impl<T: Read + Write + Seek> PortIo for PortFile<T> {
+    fn read_u8(&mut self, port: u16) -> Result<u8, Error> {
+        let mut value = [0u8; 1];
+        self.file.seek(SeekFrom::Start(port as u64))?;
+        self.file.read_exact(&mut value)?;
+        Ok(value[0])
+    }
+
+    fn write_u8(&mut self, port: u16, value: u8) -> Result<(), Error> {
+        self.file.seek(SeekFrom::Start(port as u64))?;
+        self.file.write_all(&[value])
+    }
+
+    fn write_u16(&mut self, _port: u16, _value: u16) -> Result<(), Error> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "a port file does not support 16-bit port writes",
+        ))
+    }
+}
+
+/// # Event Log Header
+#[derive(Serialize, Debug)]
+pub enum EventLogHeader {
+    /// The log has no header
+    NoHeader,
+    /// Type 1 log header
+    Type1(EventLogHeaderType1),
+    /// Header of an OEM-specific or unknown format
+    Other(Vec<u8>),
+}
+
+/// # Type 1 Event Log Header
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct EventLogHeaderType1 {
+    /// OEM-specific data
+    pub oem_reserved: [u8; 5],
+    /// Number of minutes which must pass between duplicate log entries
+    /// that use a multiple-event counter
+    pub multiple_event_time_window: u8,
+    /// Number of occurrences of a duplicate event which must pass before
+    /// the multiple-event counter of the log entry is updated
+    pub multiple_event_count_increment: u8,
+    /// CMOS RAM address of the pre-boot event log reset
+    pub pre_boot_event_log_reset_cmos_address: u8,
+    /// Bit within the CMOS RAM location which is set to clear the log
+    pub pre_boot_event_log_reset_cmos_bit_index: u8,
+    /// CMOS RAM offset of the first byte covered by the checksum
+    pub cmos_checksum_starting_offset: u8,
+    /// Number of CMOS RAM bytes covered by the checksum
+    pub cmos_checksum_byte_count: u8,
+    /// CMOS RAM offset of the checksum
+    pub cmos_checksum_checksum_offset: u8,
+    /// Header revision, 01h for this format
+    pub header_revision: u8,
+}
+
+impl EventLogHeaderType1 {
+    const LENGTH: usize = 0x10;
+
+    fn new(header: &[u8]) -> Option<Self> {
+        if header.len() < Self::LENGTH {
+            return None;
+        }
+        Some(EventLogHeaderType1 {
+            oem_reserved: header[0x00..0x05].try_into().expect("incorrect length"),
+            multiple_event_time_window: header[0x05],
+            multiple_event_count_increment: header[0x06],
+            pre_boot_event_log_reset_cmos_address: header[0x07],
+            pre_boot_event_log_reset_cmos_bit_index: header[0x08],
+            cmos_checksum_starting_offset: header[0x09],
+            cmos_checksum_byte_count: header[0x0A],
+            cmos_checksum_checksum_offset: header[0x0B],
+            header_revision: header[0x0F],
+        })
+    }
+}
+
+/// # Event Log Record
+#[derive(Serialize, Debug)]
+pub struct EventLogRecord {
+    /// Type of the event
+    pub event_type: LogTypeData,
+    /// Length of the record in bytes, including the type and length fields
+    pub length: u8,
+    /// `true` if the record has not yet been read by higher-level software
+    ///
+    /// This is the most-significant bit of the record's length field.
+    pub unread: bool,
+    /// Date and time of the event, `None` if the fields are not valid BCD
+    pub timestamp: Option<EventLogTimestamp>,
+    /// Standard format data found at the start of the variable data
+    pub data: EventLogVariableData,
+    /// All variable data of the record
+    pub variable_data: Vec<u8>,
+}
+
+impl EventLogRecord {
+    fn new(record: &[u8], format: Option<u8>) -> Self {
+        let variable_data = &record[RECORD_HEADER_LENGTH..];
+        EventLogRecord {
+            event_type: LogTypeData::from(record[0x00]),
+            length: record[0x01] & 0x7F,
+            unread: record[0x01] & 0x80 == 0x80,
+            timestamp: EventLogTimestamp::new(&record[0x02..0x08]),
+            data: EventLogVariableData::new(
+                format.map(VariableDataFormatTypeData::from),
+                variable_data,
+            ),
+            variable_data: variable_data.to_vec(),
+        }
+    }
+}
+
+/// # Event Log Timestamp
+///
+/// Date and time of an event log record.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct EventLogTimestamp {
+    /// Year, 1980 through 2079
+    pub year: u16,
+    /// Month, 1 through 12
+    pub month: u8,
+    /// Day of the month, 1 through 31
+    pub day: u8,
+    /// Hour, 0 through 23
+    pub hour: u8,
+    /// Minute, 0 through 59
+    pub minute: u8,
+    /// Second, 0 through 59
+    pub second: u8,
+}
+
+impl EventLogTimestamp {
+    /// Decodes the BCD year, month, day, hour, minute and second fields
+    ///
+    /// Two digit years 80 through 99 are 1980 through 1999, 00 through 79
+    /// are 2000 through 2079.
+    fn new(fields: &[u8]) -> Option<Self> {
+        let year = bcd_to_binary(fields[0])?;
+        Some(EventLogTimestamp {
+            year: match year {
+                80..=99 => 1900 + year as u16,
+                _ => 2000 + year as u16,
+            },
+            month: bcd_to_binary(fields[1])?,
+            day: bcd_to_binary(fields[2])?,
+            hour: bcd_to_binary(fields[3])?,
+            minute: bcd_to_binary(fields[4])?,
+            second: bcd_to_binary(fields[5])?,
+        })
+    }
+}
+
+/// # Event Log Variable Data
+///
+/// The standard format data at the start of a log record's variable data,
+/// as given by the [VariableDataFormatType] of the record's event type.
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub enum EventLogVariableData {
+    /// No standard format, the event type is not described or has no standard format data
+    NoStandardFormat,
+    /// Handle of the structure associated with the hardware element that failed
+    Handle(Handle),
+    /// Multiple-event counter
+    MultipleEvent {
+        /// Number of occurrences of the event
+        counter: u32,
+    },
+    /// Handle of the structure associated with the failing hardware element and a multiple-event counter
+    MultipleEventHandle {
+        /// Handle of the structure associated with the hardware element that failed
+        handle: Handle,
+        /// Number of occurrences of the event
+        counter: u32,
+    },
+    /// POST results bitmap (7.16.6.4)
+    PostResultsBitmap([u32; 2]),
+    /// System-management condition (7.16.6.5)
+    SystemManagementType(u32),
+    /// System-management condition and a multiple-event counter
+    MultipleEventSystemManagementType {
+        /// System-management condition (7.16.6.5)
+        system_management_type: u32,
+        /// Number of occurrences of the event
+        counter: u32,
+    },
+    /// The variable data is too short for its format, or the format is unknown
+    Invalid,
+}
+
+impl EventLogVariableData {
+    fn new(format: Option<VariableDataFormatTypeData>, data: &[u8]) -> Self {
+        let word = |offset: usize| {
+            data.get(offset..offset + 2)
+                .map(|bytes| u16::from_le_bytes(bytes.try_into().expect("incorrect length")))
+        };
+        let dword = |offset: usize| {
+            data.get(offset..offset + 4)
+                .map(|bytes| u32::from_le_bytes(bytes.try_into().expect("incorrect length")))
+        };
+
+        let format = match format {
+            Some(format) => format.value,
+            None => return EventLogVariableData::NoStandardFormat,
+        };
+        let decoded = match format {
+            VariableDataFormatType::NoStandardFormat => {
+                Some(EventLogVariableData::NoStandardFormat)
+            }
+            VariableDataFormatType::Handle => {
+                word(0).map(|handle| EventLogVariableData::Handle(Handle(handle)))
+            }
+            VariableDataFormatType::MultipleEvent => {
+                dword(0).map(|counter| EventLogVariableData::MultipleEvent { counter })
+            }
+            VariableDataFormatType::MultipleEventHandle => word(0).and_then(|handle| {
+                dword(2).map(|counter| EventLogVariableData::MultipleEventHandle {
+                    handle: Handle(handle),
+                    counter,
+                })
+            }),
+            VariableDataFormatType::PostResultsBitmap => dword(0).and_then(|first| {
+                dword(4).map(|second| EventLogVariableData::PostResultsBitmap([first, second]))
+            }),
+            VariableDataFormatType::SystemManagementType => {
+                dword(0).map(EventLogVariableData::SystemManagementType)
+            }
+            VariableDataFormatType::MultipleEventSystemManagementType => {
+                dword(0).and_then(|system_management_type| {
+                    dword(4).map(|counter| {
+                        EventLogVariableData::MultipleEventSystemManagementType {
+                            system_management_type,
+                            counter,
+                        }
+                    })
+                })
+            }
+            VariableDataFormatType::None => None,
+        };
+        decoded.unwrap_or(EventLogVariableData::Invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::UndefinedStruct;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_event_log() {
+        // Memory-mapped log of 0x40 bytes at 0x1000 with a type 1 header;
+        // POST error (08h) uses the POST results bitmap, single-bit ECC (01h)
+        // the handle and multiple-event counter.
+        let struct_type15 = vec![
+            0x0F, 0x1B, 0x3D, 0x00, 0x40, 0x00, 0x00, 0x00, 0x10, 0x00, 0x03, 0x01, 0x05, 0x00,
+            0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x01, 0x02, 0x02, 0x08, 0x04, 0x01, 0x03, 0x00,
+            0x00,
+        ];
+        let parts = UndefinedStruct::new(&struct_type15);
+        let event_log = SMBiosSystemEventLog::new(&parts);
+
+        let mut memory = vec![0u8; 0x1040];
+        let log_area = &mut memory[0x1000..];
+        log_area[0x05] = 60; // multiple event time window
+        log_area[0x06] = 2; // multiple event count increment
+        log_area[0x0F] = 1; // header revision
+        log_area[0x10..0x20].copy_from_slice(&[
+            0x08, 0x90, 0x24, 0x03, 0x15, 0x13, 0x45, 0x59, // POST error, unread
+            0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // POST results bitmap
+        ]);
+        log_area[0x20..0x2E].copy_from_slice(&[
+            0x01, 0x0E, 0x99, 0x12, 0x31, 0x23, 0x59, 0x00, // single-bit ECC, read
+            0x08, 0x00, 0x03, 0x00, 0x00, 0x00, // handle 8, counter 3
+        ]);
+        log_area[0x2E] = 0xFF; // end of log
+
+        let log = EventLog::read(&event_log, &mut Cursor::new(memory)).unwrap();
+        match &log.header {
+            EventLogHeader::Type1(header) => {
+                assert_eq!(header.multiple_event_time_window, 60);
+                assert_eq!(header.multiple_event_count_increment, 2);
+                assert_eq!(header.header_revision, 1);
+            }
+            other => panic!("expected type 1 header, got {:?}", other),
+        }
+
+        assert_eq!(log.records.len(), 2);
+        let post_error = &log.records[0];
+        assert_eq!(*post_error.event_type, LogType::PostError);
+        assert_eq!(post_error.length, 0x10);
+        assert!(post_error.unread);
+        assert_eq!(
+            post_error.timestamp,
+            Some(EventLogTimestamp {
+                year: 2024,
+                month: 3,
+                day: 15,
+                hour: 13,
+                minute: 45,
+                second: 59
+            })
+        );
+        assert_eq!(
+            post_error.data,
+            EventLogVariableData::PostResultsBitmap([0x100, 0])
+        );
+
+        let ecc_error = &log.records[1];
+        assert_eq!(*ecc_error.event_type, LogType::SingleBitEccMemoryError);
+        assert!(!ecc_error.unread);
+        assert_eq!(ecc_error.timestamp.unwrap().year, 1999);
+        assert_eq!(
+            ecc_error.data,
+            EventLogVariableData::MultipleEventHandle {
+                handle: Handle(8),
+                counter: 3
+            }
+        );
+    }
+
+    /// Port space with the index and data ports of a log area
+    struct SimulatedPorts {
+        index_port: u16,
+        data_port: u16,
+        index: u16,
+        log_area: Vec<u8>,
+    }
+
+    impl PortIo for SimulatedPorts {
+        fn read_u8(&mut self, port: u16) -> Result<u8, Error> {
+            if port != self.data_port {
+                return Err(Error::new(ErrorKind::InvalidInput, "not the data port"));
+            }
+            Ok(self.log_area[self.index as usize])
+        }
+
+        fn write_u8(&mut self, port: u16, value: u8) -> Result<(), Error> {
+            if port == self.index_port {
+                self.index = (self.index & 0xFF00) | value as u16;
+            } else if port == self.index_port + 1 {
+                self.index = (self.index & 0x00FF) | (value as u16) << 8;
+            } else {
+                return Err(Error::new(ErrorKind::InvalidInput, "not an index port"));
+            }
+            Ok(())
+        }
+
+        fn write_u16(&mut self, port: u16, value: u16) -> Result<(), Error> {
+            if port != self.index_port {
+                return Err(Error::new(ErrorKind::InvalidInput, "not the index port"));
+            }
+            self.index = value;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_event_log_indexed_io() {
+        // 2 8-bit index ports at 0C80h/0C81h, data port at 0C84h, log of 0x120 bytes
+        let struct_type15 = vec![
+            0x0F, 0x19, 0x3D, 0x00, 0x20, 0x01, 0x00, 0x00, 0x10, 0x00, 0x01, 0x01, 0x05, 0x00,
+            0x00, 0x00, 0x80, 0x0C, 0x84, 0x0C, 0x01, 0x01, 0x02, 0x08, 0x04, 0x00, 0x00,
+        ];
+        let parts = UndefinedStruct::new(&struct_type15);
+        let event_log = SMBiosSystemEventLog::new(&parts);
+        assert_eq!(
+            event_log.decoded_access_method_address(),
+            Some(AccessMethodAddress::IndexedIO {
+                index_port: 0x0C80,
+                data_port: 0x0C84
+            })
+        );
+
+        let mut log_area = vec![0xFFu8; 0x120];
+        log_area[0x00..0x10].copy_from_slice(&[0; 0x10]);
+        log_area[0x0F] = 1; // header revision
+
+        // Records fill the log past the 8-bit index range
+        for offset in (0x10..0x110).step_by(0x10) {
+            log_area[offset..offset + 0x10].copy_from_slice(&[
+                0x08, 0x10, 0x21, 0x01, 0x02, 0x03, 0x04, 0x05, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00,
+            ]);
+        }
+        let mut ports = SimulatedPorts {
+            index_port: 0x0C80,
+            data_port: 0x0C84,
+            index: 0,
+            log_area,
+        };
+
+        let log = EventLog::read_indexed_io(&event_log, &mut ports).unwrap();
+        assert_eq!(log.records.len(), 16);
+        let last = log.records.last().unwrap();
+        assert_eq!(*last.event_type, LogType::PostError);
+        assert_eq!(last.timestamp.unwrap().year, 2021);
+        assert_eq!(last.data, EventLogVariableData::PostResultsBitmap([2, 0]));
+
+        let error = EventLog::read(&event_log, &mut Cursor::new(vec![0u8; 0x100])).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn test_event_log_unsupported_access_method() {
+        // GPNV access method
+        let struct_type15 = vec![
+            0x0F, 0x17, 0x3D, 0x00, 0x40, 0x00, 0x00, 0x00, 0x10, 0x00, 0x04, 0x01, 0x05, 0x00,
+            0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00,
+        ];
+        let parts = UndefinedStruct::new(&struct_type15);
+        let event_log = SMBiosSystemEventLog::new(&parts);
+
+        let error = EventLog::read(&event_log, &mut Cursor::new(vec![0u8; 0x100])).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Unsupported);
+        let error = EventLog::read_indexed_io(
+            &event_log,
+            &mut PortFile::new(Cursor::new(vec![0u8; 0x100])),
+        )
+        .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Unsupported);
+
+        // A port file accesses ports a byte at a time
+        let mut port_file = PortFile::new(Cursor::new(vec![0u8; 0x100]));
+        let error = port_file.write_u16(0x80, 0x1234).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Unsupported);
+        port_file.write_u8(0x80, 0x12).unwrap();
+        assert_eq!(port_file.read_u8(0x80).unwrap(), 0x12);
+    }
+}
@@ -0,0 +1,165 @@
+//! Additional Information (Type 40) entries resolved against the fields they annotate.
+//!
+//! Each Additional Information entry names a structure by handle and a field
+//! within it by offset. [FieldAnnotation] joins the entry with the structure
+//! it targets and, where the offset starts a field read by the structure's
+//! typed reader, the name of that field's accessor, e.g. "serial_number",
+//! as given by [SMBiosStruct::field_name].
+use crate::core::{Handle, SMBiosData, UndefinedStruct};
+use crate::structs::*;
+use serde::Serialize;
+use std::fmt;
+
+/// # Field Annotation
+///
+/// An Additional Information entry resolved against its target structure.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct FieldAnnotation {
+    /// Handle of the Additional Information structure holding the entry
+    pub additional_information_handle: Handle,
+    /// Handle of the structure the entry annotates
+    pub referenced_handle: Handle,
+    /// Offset of the annotated field within the referenced structure
+    pub referenced_offset: u8,
+    /// Type of the referenced structure, `None` if no structure has the referenced handle
+    pub target_type: Option<u8>,
+    /// Accessor name of the annotated field, `None` if the offset does
+    /// not start a field known to the target's typed reader
+    pub field_name: Option<&'static str>,
+    /// Additional string for the field
+    pub string: Option<String>,
+    /// Additional value for the field
+    pub value: Vec<u8>,
+}
+
+impl fmt::Display for FieldAnnotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02X}", self.referenced_offset)?;
+        if let Some(field_name) = self.field_name {
+            write!(f, " {}", field_name)?;
+        }
+        write!(f, ":")?;
+        if let Some(string) = &self.string {
+            write!(f, " {:?}", string)?;
+        }
+        if !self.value.is_empty() {
+            write!(f, " [")?;
+            for (index, byte) in self.value.iter().enumerate() {
+                let separator = if index == 0 { "" } else { " " };
+                write!(f, "{}{:02X}", separator, byte)?;
+            }
+            write!(f, "]")?;
+        }
+        write!(f, " (Handle {:#06X})", *self.additional_information_handle)
+    }
+}
+
+impl FieldAnnotation {
+    /// The structure the entry annotates
+    pub fn target<'a>(&self, data: &'a SMBiosData) -> Option<&'a UndefinedStruct> {
+        data.find_by_handle(&self.referenced_handle)
+    }
+}
+
+impl SMBiosData {
+    /// Resolves every Additional Information entry of the table
+    /// against the structure and field it annotates
+    pub fn field_annotations(&self) -> Vec<FieldAnnotation> {
+        self.field_annotations_where(|_| true)
+    }
+
+    /// Additional Information entries annotating the structure with the given handle
+    pub fn field_annotations_for(&self, handle: Handle) -> Vec<FieldAnnotation> {
+        self.field_annotations_where(|referenced_handle| referenced_handle == handle)
+    }
+
+    /// Resolves the entries whose referenced handle satisfies `predicate`
+    fn field_annotations_where(&self, predicate: impl Fn(Handle) -> bool) -> Vec<FieldAnnotation> {
+        let mut annotations = Vec::new();
+        for additional_information in self.collect::<SMBiosAdditionalInformation<'_>>() {
+            let additional_information_handle = additional_information.parts().header.handle();
+            for entry in additional_information.entry_iterator() {
+                let (referenced_handle, referenced_offset) =
+                    match (entry.referenced_handle(), entry.referenced_offset()) {
+                        (Some(handle), Some(offset)) if predicate(handle) => (handle, offset),
+                        _ => continue,
+                    };
+                let target = self.find_by_handle(&referenced_handle);
+
+                annotations.push(FieldAnnotation {
+                    additional_information_handle,
+                    referenced_handle,
+                    referenced_offset,
+                    target_type: target.map(|target| target.header.struct_type()),
+                    field_name: target.and_then(|target| {
+                        target
+                            .defined_struct()
+                            .field_name(referenced_offset as usize)
+                    }),
+                    string: entry
+                        .string()
+                        .to_utf8_lossy()
+                        .filter(|string| !string.is_empty()),
+                    value: entry.value().unwrap_or_default().to_vec(),
+                });
+            }
+        }
+        annotations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_annotations() {
+        // System Information at handle 1
+        let mut table = vec![
+            0x01, 0x1B, 0x01, 0x00, 0x01, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00,
+        ];
+        table.extend_from_slice(b"Acme\0SN123\0\0");
+        // Additional Information at handle 0x40 annotating serial_number (07h),
+        // offset 03h (inside the header) and handle 0x99 which does not exist
+        table.extend_from_slice(&[
+            0x28, 0x17, 0x40, 0x00, 0x03, 0x06, 0x01, 0x00, 0x07, 0x01, 0x00, 0x06, 0x01, 0x00,
+            0x03, 0x00, 0x05, 0x06, 0x99, 0x00, 0x04, 0x00, 0x07,
+        ]);
+        table.extend_from_slice(b"Asset serial\0\0");
+        let data = SMBiosData::from_vec_and_version(table, None);
+
+        let annotations = data.field_annotations();
+        assert_eq!(annotations.len(), 3);
+        assert_eq!(
+            annotations[0],
+            FieldAnnotation {
+                additional_information_handle: Handle(0x40),
+                referenced_handle: Handle(1),
+                referenced_offset: 0x07,
+                target_type: Some(1),
+                field_name: Some("serial_number"),
+                string: Some("Asset serial".to_string()),
+                value: vec![0x00],
+            }
+        );
+        assert_eq!(annotations[1].field_name, None);
+        assert_eq!(annotations[1].value, vec![0x05]);
+        assert_eq!(annotations[2].target_type, None);
+        assert!(annotations[2].target(&data).is_none());
+        assert_eq!(
+            annotations[0]
+                .target(&data)
+                .map(|target| target.header.struct_type()),
+            Some(1)
+        );
+
+        assert_eq!(data.field_annotations_for(Handle(1)).len(), 2);
+        assert!(data.field_annotations_for(Handle(0x40)).is_empty());
+        assert_eq!(
+            annotations[0].to_string(),
+            "07 serial_number: \"Asset serial\" [00] (Handle 0x0040)"
+        );
+        assert_eq!(annotations[1].to_string(), "03: [05] (Handle 0x0040)");
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! When testing this library it is useful to read stored
 //! raw data and then load it into the structures.
+use crate::convert::{read_table, TableFormat};
 use crate::core::SMBiosData;
 use crate::windows::WinSMBiosData;
 use std::io::{BufWriter, Error, Write};
@@ -12,11 +13,14 @@ use std::{
 
 /// Loads raw smbios data from a file and returns [SMBiosData] or [std::io::Error] on error.
 ///
-/// Currently supports reading raw files containing only SMBIOS table data or
-/// Windows raw files containing the windows header and SMBIOS table data.
+/// Currently supports reading raw files containing only SMBIOS table data,
+/// Windows raw files containing the windows header and SMBIOS table data, and
+/// `dmidecode --dump-bin` files containing an entry point and SMBIOS table data.
 pub fn load_smbios_data_from_file(file_path: &Path) -> Result<SMBiosData, Error> {
     let data = read(file_path)?;
-    if WinSMBiosData::is_valid_win_smbios_data(&data) {
+    if TableFormat::detect(&data) == TableFormat::Dmidecode {
+        read_table(data, TableFormat::Dmidecode)
+    } else if WinSMBiosData::is_valid_win_smbios_data(&data) {
         let win_smbios = WinSMBiosData::new(data)
             .expect("Structure shouldn't be invalid it was already checked.");
         Ok(win_smbios.smbios_data)
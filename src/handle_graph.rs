@@ -0,0 +1,348 @@
+//! Handle references between structures.
+//!
+//! Many structures refer to others by [Handle], for example a
+//! [SMBiosMemoryDevice] names its [SMBiosPhysicalMemoryArray]. [HandleGraph]
+//! collects every such reference in a table so that references can be
+//! followed in either direction without per-type code.
+use crate::core::{Handle, SMBiosData, UndefinedStruct};
+use crate::structs::*;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Handle value meaning the field refers to no structure
+const NO_HANDLE: u16 = 0xFFFF;
+
+/// Handle value meaning the firmware does not provide the information
+const HANDLE_NOT_PROVIDED: u16 = 0xFFFE;
+
+/// # Handle Reference
+///
+/// A reference from a field of one structure to another structure.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct HandleEdge {
+    /// Handle of the structure containing the field
+    pub from: Handle,
+    /// Name of the field containing the handle, as named by the structure's accessor
+    pub field: &'static str,
+    /// Handle the field refers to
+    pub to: Handle,
+}
+
+/// # Handle Reference Graph
+///
+/// Forward and reverse handle references of every structure in a table.
+///
+/// Fields holding FFFFh (no structure) or FFFEh (not provided) are not
+/// references and are left out. References to handles which are not in
+/// the table are kept and reported by [HandleGraph::dangling].
+#[derive(Debug)]
+pub struct HandleGraph {
+    edges: Vec<HandleEdge>,
+    struct_types: HashMap<Handle, u8>,
+    forward: HashMap<Handle, Vec<usize>>,
+    reverse: HashMap<Handle, Vec<usize>>,
+}
+
+impl HandleGraph {
+    /// Builds the reference graph of a table
+    pub fn new(data: &SMBiosData) -> Self {
+        let mut graph = HandleGraph {
+            edges: Vec::new(),
+            struct_types: HashMap::new(),
+            forward: HashMap::new(),
+            reverse: HashMap::new(),
+        };
+
+        for undefined_struct in data.iter() {
+            let from = undefined_struct.header.handle();
+            graph
+                .struct_types
+                .insert(from, undefined_struct.header.struct_type());
+
+            for (field, to) in struct_references(undefined_struct) {
+                if *to == NO_HANDLE || *to == HANDLE_NOT_PROVIDED {
+                    continue;
+                }
+                let index = graph.edges.len();
+                graph.edges.push(HandleEdge { from, field, to });
+                graph.forward.entry(from).or_default().push(index);
+                graph.reverse.entry(to).or_default().push(index);
+            }
+        }
+
+        graph
+    }
+
+    /// All references in table order
+    pub fn edges(&self) -> &[HandleEdge] {
+        self.edges.as_slice()
+    }
+
+    /// The structure type of the structure with the given handle, if it is in the table
+    pub fn struct_type(&self, handle: Handle) -> Option<u8> {
+        self.struct_types.get(&handle).copied()
+    }
+
+    /// References made by the structure with the given handle
+    pub fn references_from(&self, handle: Handle) -> impl Iterator<Item = &HandleEdge> + '_ {
+        self.edges_at(self.forward.get(&handle))
+    }
+
+    /// References made to the structure with the given handle
+    pub fn references_to(&self, handle: Handle) -> impl Iterator<Item = &HandleEdge> + '_ {
+        self.edges_at(self.reverse.get(&handle))
+    }
+
+    /// References to handles which are not in the table
+    pub fn dangling(&self) -> impl Iterator<Item = &HandleEdge> + '_ {
+        self.edges
+            .iter()
+            .filter(move |edge| !self.struct_types.contains_key(&edge.to))
+    }
+
+    fn edges_at<'a>(
+        &'a self,
+        indexes: Option<&'a Vec<usize>>,
+    ) -> impl Iterator<Item = &'a HandleEdge> + 'a {
+        indexes
+            .into_iter()
+            .flatten()
+            .map(move |&index| &self.edges[index])
+    }
+}
+
+impl Serialize for HandleGraph {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.edges.serialize(serializer)
+    }
+}
+
+/// Returns the handle fields of a structure, named after their accessors
+fn struct_references(undefined_struct: &UndefinedStruct) -> Vec<(&'static str, Handle)> {
+    let mut references = Vec::new();
+    let mut push = |field: &'static str, handle: Option<Handle>| {
+        if let Some(handle) = handle {
+            references.push((field, handle));
+        }
+    };
+
+    match undefined_struct.defined_struct() {
+        DefinedStruct::BaseBoardInformation(baseboard) => {
+            push("chassis_handle", baseboard.chassis_handle());
+            for handle in baseboard.contained_object_handle_iterator() {
+                push("contained_object_handles", Some(handle));
+            }
+        }
+        DefinedStruct::ProcessorInformation(processor) => {
+            push("l1cache_handle", processor.l1cache_handle());
+            push("l2cache_handle", processor.l2cache_handle());
+            push("l3cache_handle", processor.l3cache_handle());
+        }
+        DefinedStruct::GroupAssociations(group) => {
+            for item in group.item_iterator() {
+                push("item_handle", item.item_handle());
+            }
+        }
+        DefinedStruct::MemoryControllerInformation(controller) => {
+            for handle in controller.memory_module_handle_iterator() {
+                push("memory_module_handles", Some(handle));
+            }
+        }
+        DefinedStruct::PhysicalMemoryArray(array) => {
+            push(
+                "memory_error_information_handle",
+                array.memory_error_information_handle(),
+            );
+        }
+        DefinedStruct::MemoryDevice(device) => {
+            push(
+                "physical_memory_array_handle",
+                device.physical_memory_array_handle(),
+            );
+            push(
+                "memory_error_information_handle",
+                device.memory_error_information_handle(),
+            );
+        }
+        DefinedStruct::MemoryArrayMappedAddress(mapped_address) => {
+            push(
+                "physical_memory_array_handle",
+                mapped_address.physical_memory_array_handle(),
+            );
+        }
+        DefinedStruct::MemoryDeviceMappedAddress(mapped_address) => {
+            push(
+                "memory_device_handle",
+                mapped_address.memory_device_handle(),
+            );
+            push(
+                "memory_array_mapped_address_handle",
+                mapped_address.memory_array_mapped_address_handle(),
+            );
+        }
+        DefinedStruct::CoolingDevice(cooling_device) => {
+            push(
+                "temperature_probe_handle",
+                cooling_device.temperature_probe_handle(),
+            );
+        }
+        DefinedStruct::ManagementDeviceComponent(component) => {
+            push(
+                "management_device_handle",
+                component.management_device_handle(),
+            );
+            push("component_handle", component.component_handle());
+            push("threshold_handle", component.threshold_handle());
+        }
+        DefinedStruct::MemoryChannel(channel) => {
+            for load_handle_pair in channel.load_handle_pairs_iterator() {
+                push("memory_device_handle", load_handle_pair.handle());
+            }
+        }
+        DefinedStruct::SystemPowerSupply(power_supply) => {
+            push(
+                "input_voltage_probe_handle",
+                power_supply.input_voltage_probe_handle(),
+            );
+            push(
+                "cooling_device_handle",
+                power_supply.cooling_device_handle(),
+            );
+            push(
+                "input_current_probe_handle",
+                power_supply.input_current_probe_handle(),
+            );
+        }
+        DefinedStruct::AdditionalInformation(additional_information) => {
+            for entry in additional_information.entry_iterator() {
+                push("referenced_handle", entry.referenced_handle());
+            }
+        }
+        DefinedStruct::ProcessorAdditionalInformation(additional_information) => {
+            push(
+                "referenced_handle",
+                additional_information.referenced_handle(),
+            );
+        }
+        DefinedStruct::FirmwareInventoryInformation(firmware) => {
+            for handle in firmware.associated_component_handle_iterator() {
+                push("associated_component_handles", Some(handle));
+            }
+        }
+        DefinedStruct::StringProperty(string_property) => {
+            push("parent_handle", string_property.parent_handle());
+        }
+        _ => (),
+    }
+
+    references
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_handle_graph() {
+        let mut path = PathBuf::new();
+        path.push(".");
+        path.push("tests");
+        path.push("jeffgerlap_3_2_0");
+        path.set_extension("dat");
+
+        let data = crate::load_smbios_data_from_file(&path).unwrap();
+        let graph = HandleGraph::new(&data);
+
+        let processor_references: Vec<(&str, Handle)> = graph
+            .references_from(Handle(6))
+            .map(|edge| (edge.field, edge.to))
+            .collect();
+        assert_eq!(
+            processor_references,
+            vec![
+                ("l1cache_handle", Handle(3)),
+                ("l2cache_handle", Handle(4)),
+                ("l3cache_handle", Handle(5)),
+            ]
+        );
+
+        let l2_referrers: Vec<&HandleEdge> = graph.references_to(Handle(4)).collect();
+        assert_eq!(
+            l2_referrers,
+            vec![&HandleEdge {
+                from: Handle(6),
+                field: "l2cache_handle",
+                to: Handle(4)
+            }]
+        );
+        assert_eq!(graph.struct_type(Handle(4)), Some(7));
+
+        // Both memory devices and the array mapped address refer to the memory array
+        let array_handle = data
+            .first::<SMBiosPhysicalMemoryArray<'_>>()
+            .unwrap()
+            .parts()
+            .header
+            .handle();
+        assert_eq!(
+            graph
+                .references_to(array_handle)
+                .filter(|edge| edge.field == "physical_memory_array_handle")
+                .count(),
+            3
+        );
+        assert_eq!(graph.dangling().count(), 0);
+    }
+
+    #[test]
+    fn test_handle_graph_memory_controller() {
+        // Memory controller (type 5) with two memory modules, one of them missing
+        let mut table = vec![
+            0x05, 0x14, 0x50, 0x00, 0x03, 0x04, 0x04, 0x04, 0x0B, 0x04, 0x00, 0x80, 0x01, 0x04,
+            0x02, 0x51, 0x00, 0x52, 0x00, 0x04, 0x00, 0x00,
+        ];
+        // Memory module (type 6)
+        table.extend_from_slice(&[
+            0x06, 0x0C, 0x51, 0x00, 0x00, 0x01, 0x02, 0x00, 0x0B, 0x0B, 0x00, 0x00, 0x00, 0x00,
+        ]);
+        let data = SMBiosData::from_vec_and_version(table, None);
+        let graph = HandleGraph::new(&data);
+
+        let controller_references: Vec<(&str, Handle)> = graph
+            .references_from(Handle(0x50))
+            .map(|edge| (edge.field, edge.to))
+            .collect();
+        assert_eq!(
+            controller_references,
+            vec![
+                ("memory_module_handles", Handle(0x51)),
+                ("memory_module_handles", Handle(0x52)),
+            ]
+        );
+        assert_eq!(graph.dangling().count(), 1);
+    }
+
+    #[test]
+    fn test_handle_graph_dangling() {
+        // Cooling device (type 27) referring to a missing temperature probe
+        let struct_type27 = vec![
+            0x1B, 0x0C, 0x20, 0x00, 0x40, 0x00, 0x63, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let data = SMBiosData::from_vec_and_version(struct_type27, None);
+        let graph = HandleGraph::new(&data);
+
+        let dangling: Vec<&HandleEdge> = graph.dangling().collect();
+        assert_eq!(
+            dangling,
+            vec![&HandleEdge {
+                from: Handle(0x20),
+                field: "temperature_probe_handle",
+                to: Handle(0x40)
+            }]
+        );
+    }
+}
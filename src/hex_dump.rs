@@ -0,0 +1,198 @@
+//! Renders structures as raw hex for debugging.
+//!
+//! Offsets are relative to the start of the structure and therefore match
+//! the offsets given by the SMBIOS specification (the header occupies
+//! offsets 00h through 03h).
+//!
+//! A table dump also lists the Additional Information (Type 40) entries
+//! annotating each structure, see [crate::FieldAnnotation].
+use crate::core::{Header, SMBiosData, UndefinedStruct};
+use std::fmt;
+
+/// Number of bytes shown per line of the formatted area
+const BYTES_PER_LINE: usize = 16;
+
+/// # Structure Hex Dump
+///
+/// Displays the header, the formatted area and the string-set of an [UndefinedStruct].
+///
+/// Example output:
+/// ```text
+/// Handle 0x0011, DMI type 24, 5 bytes
+///   Header: 18 05 11 00
+///   Formatted area:
+///     04: 02                                               .
+///   Strings: none
+/// ```
+pub struct StructHexDump<'a>(pub &'a UndefinedStruct);
+
+/// # Table Hex Dump
+///
+/// Displays a [StructHexDump] for every structure in an [SMBiosData] table,
+/// each followed by the [crate::FieldAnnotation]s of the structure.
+///
+/// Example annotations:
+/// ```text
+///   Annotations:
+///     07 serial_number: "Asset serial" [00] (Handle 0x0040)
+/// ```
+pub struct TableHexDump<'a>(pub &'a SMBiosData);
+
+impl UndefinedStruct {
+    /// Returns a displayable hex dump of this structure
+    pub fn hex_dump(&self) -> StructHexDump<'_> {
+        StructHexDump(self)
+    }
+}
+
+impl SMBiosData {
+    /// Returns a displayable hex dump of every structure in the table
+    pub fn hex_dump(&self) -> TableHexDump<'_> {
+        TableHexDump(self)
+    }
+}
+
+impl fmt::Display for StructHexDump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts = self.0;
+        writeln!(
+            f,
+            "Handle {:#06X}, DMI type {}, {} bytes",
+            *parts.header.handle(),
+            parts.header.struct_type(),
+            parts.header.length()
+        )?;
+
+        write!(f, "  Header:")?;
+        for byte in parts.header.iter() {
+            write!(f, " {:02X}", byte)?;
+        }
+        writeln!(f)?;
+
+        let formatted_area = parts.fields.get(Header::SIZE..).unwrap_or(&[]);
+        if formatted_area.is_empty() {
+            writeln!(f, "  Formatted area: none")?;
+        } else {
+            writeln!(f, "  Formatted area:")?;
+            for (line_index, line) in formatted_area.chunks(BYTES_PER_LINE).enumerate() {
+                write!(f, "    {:02X}:", Header::SIZE + line_index * BYTES_PER_LINE)?;
+                for byte in line {
+                    write!(f, " {:02X}", byte)?;
+                }
+                // Pad short lines so the ASCII column stays aligned
+                for _ in line.len()..BYTES_PER_LINE {
+                    write!(f, "   ")?;
+                }
+                write!(f, "  ")?;
+                for &byte in line {
+                    let printable = byte.is_ascii_graphic() || byte == b' ';
+                    write!(f, "{}", if printable { byte as char } else { '.' })?;
+                }
+                writeln!(f)?;
+            }
+        }
+
+        if parts.strings.iter().len() == 0 {
+            writeln!(f, "  Strings: none")
+        } else {
+            writeln!(f, "  Strings:")?;
+            for (index, string) in parts.strings.iter().enumerate() {
+                writeln!(
+                    f,
+                    "    {}: {:?}",
+                    index + 1,
+                    String::from_utf8_lossy(string)
+                )?;
+            }
+            Ok(())
+        }
+    }
+}
+
+impl fmt::Display for TableHexDump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let annotations = self.0.field_annotations();
+        for (index, undefined_struct) in self.0.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", undefined_struct.hex_dump())?;
+
+            let handle = undefined_struct.header.handle();
+            let mut struct_annotations = annotations
+                .iter()
+                .filter(|annotation| annotation.referenced_handle == handle)
+                .peekable();
+            if struct_annotations.peek().is_some() {
+                writeln!(f, "  Annotations:")?;
+                for annotation in struct_annotations {
+                    writeln!(f, "    {}", annotation)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_struct_hex_dump() {
+        let struct_type2 = vec![
+            0x02, 0x0F, 0x10, 0x00, 0x01, 0x02, 0x00, 0x03, 0x04, 0x09, 0x05, 0x11, 0x00, 0x0A,
+            0x00, b'A', b'c', b'm', b'e', 0x00, b'B', b'o', b'a', b'r', b'd', 0x00, b'1', 0x00,
+            b'S', b'N', 0x00, 0xC3, 0x28, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type2);
+        assert_eq!(
+            format!("{}", parts.hex_dump()),
+            "Handle 0x0010, DMI type 2, 15 bytes\n\
+            \x20 Header: 02 0F 10 00\n\
+            \x20 Formatted area:\n\
+            \x20   04: 01 02 00 03 04 09 05 11 00 0A 00                 ...........\n\
+            \x20 Strings:\n\
+            \x20   1: \"Acme\"\n\
+            \x20   2: \"Board\"\n\
+            \x20   3: \"1\"\n\
+            \x20   4: \"SN\"\n\
+            \x20   5: \"\u{FFFD}(\"\n"
+        );
+    }
+
+    #[test]
+    fn test_struct_hex_dump_without_strings() {
+        let struct_type127 = vec![0x7F, 0x04, 0xFF, 0xFE, 0x00, 0x00];
+
+        let parts = UndefinedStruct::new(&struct_type127);
+        assert_eq!(
+            format!("{}", parts.hex_dump()),
+            "Handle 0xFEFF, DMI type 127, 4 bytes\n\
+            \x20 Header: 7F 04 FF FE\n\
+            \x20 Formatted area: none\n\
+            \x20 Strings: none\n"
+        );
+    }
+
+    #[test]
+    fn test_table_hex_dump_annotations() {
+        // Hardware Security at handle 0x11 and an Additional Information
+        // entry annotating its settings (04h)
+        let mut table = vec![0x18, 0x05, 0x11, 0x00, 0x02, 0x00, 0x00];
+        table.extend_from_slice(&[
+            0x28, 0x0B, 0x40, 0x00, 0x01, 0x06, 0x11, 0x00, 0x04, 0x01, 0x0F, b'L', b'o', b'c',
+            b'k', b'e', b'd', 0x00, 0x00,
+        ]);
+        let data = SMBiosData::from_vec_and_version(table, None);
+
+        let dump = format!("{}", data.hex_dump());
+        assert!(dump.contains(
+            "  Strings: none\n\
+            \x20 Annotations:\n\
+            \x20   04 hardware_security_settings: \"Locked\" [0F] (Handle 0x0040)\n"
+        ));
+        assert_eq!(dump.matches("Annotations:").count(), 1);
+    }
+}
@@ -0,0 +1,526 @@
+//! A one-screen system inventory built from an SMBIOS table.
+//!
+//! [Inventory] gathers the handful of facts support staff usually ask
+//! for (vendor, model, serial, BIOS, processors, memory, slots, battery,
+//! TPM and jumper settings) and renders them as text via [fmt::Display] or as JSON via
+//! [Serialize].
+use crate::core::{SMBiosData, SMBiosString};
+use crate::structs::*;
+use serde::Serialize;
+use std::fmt;
+
+/// # System Inventory
+#[derive(Serialize, Debug)]
+pub struct Inventory {
+    /// System identification
+    pub system: SystemInventory,
+    /// BIOS identification
+    pub bios: BiosInventory,
+    /// Processor sockets, populated or not
+    pub processors: Vec<ProcessorInventory>,
+    /// Installed memory
+    pub memory: MemoryInventory,
+    /// System slots
+    pub slots: Vec<SlotInventory>,
+    /// Portable batteries
+    pub batteries: Vec<BatteryInventory>,
+    /// TPM device, if present
+    pub tpm: Option<TpmInventory>,
+    /// Jumper and switch settings
+    ///
+    /// Taken from System Configuration Options (Type 12).
+    pub configuration_options: Vec<SystemConfigurationOption>,
+}
+
+/// # System Identification
+///
+/// Taken from System Information (Type 1) and System Enclosure or Chassis (Type 3).
+#[derive(Serialize, Debug, Default)]
+pub struct SystemInventory {
+    /// System manufacturer
+    pub manufacturer: Option<String>,
+    /// System product name
+    pub product_name: Option<String>,
+    /// System version
+    pub version: Option<String>,
+    /// System serial number
+    pub serial_number: Option<String>,
+    /// System UUID
+    pub uuid: Option<String>,
+    /// Chassis type
+    pub chassis_type: Option<String>,
+}
+
+/// # BIOS Identification
+///
+/// Taken from BIOS Information (Type 0).
+#[derive(Serialize, Debug, Default)]
+pub struct BiosInventory {
+    /// BIOS vendor
+    pub vendor: Option<String>,
+    /// BIOS version
+    pub version: Option<String>,
+    /// BIOS release date
+    pub release_date: Option<String>,
+}
+
+/// # Processor Socket
+///
+/// Taken from Processor Information (Type 4).
+#[derive(Serialize, Debug)]
+pub struct ProcessorInventory {
+    /// Socket designation
+    pub socket: Option<String>,
+    /// `true` if the socket holds a processor
+    pub populated: bool,
+    /// Processor manufacturer
+    pub manufacturer: Option<String>,
+    /// Processor version (model name)
+    pub version: Option<String>,
+    /// Number of cores, if known
+    pub core_count: Option<u16>,
+    /// Number of threads, if known
+    pub thread_count: Option<u16>,
+    /// Maximum speed in MHz, if known
+    pub max_speed_mhz: Option<u16>,
+}
+
+/// # Installed Memory
+///
+/// Taken from Memory Device (Type 17).
+#[derive(Serialize, Debug)]
+pub struct MemoryInventory {
+    /// Total size in bytes of all populated devices with a known size
+    pub total_bytes: u64,
+    /// Memory device sockets, populated or not
+    pub devices: Vec<MemoryDeviceInventory>,
+}
+
+/// # Memory Device Socket
+#[derive(Serialize, Debug)]
+pub struct MemoryDeviceInventory {
+    /// Device locator
+    pub device_locator: Option<String>,
+    /// Bank locator
+    pub bank_locator: Option<String>,
+    /// `true` if a memory device is installed in the socket
+    pub populated: bool,
+    /// Size in bytes, if known
+    pub size_bytes: Option<u64>,
+    /// Memory type (e.g. Ddr4)
+    pub memory_type: Option<String>,
+    /// Maximum capable speed in MT/s, if known
+    pub speed_mts: Option<u32>,
+    /// Configured speed in MT/s, if known
+    pub configured_speed_mts: Option<u32>,
+    /// Manufacturer
+    pub manufacturer: Option<String>,
+    /// Part number
+    pub part_number: Option<String>,
+}
+
+/// # System Slot
+///
+/// Taken from System Slots (Type 9).
+#[derive(Serialize, Debug)]
+pub struct SlotInventory {
+    /// Slot designation
+    pub designation: Option<String>,
+    /// Slot type
+    pub slot_type: Option<String>,
+    /// `true` if the slot is a PCI Express slot
+    pub pci_express: bool,
+    /// `true` if the slot is in use
+    pub in_use: bool,
+}
+
+/// # Portable Battery
+///
+/// Taken from Portable Battery (Type 22).
+#[derive(Serialize, Debug)]
+pub struct BatteryInventory {
+    /// Battery location
+    pub location: Option<String>,
+    /// Battery manufacturer
+    pub manufacturer: Option<String>,
+    /// Battery device name
+    pub device_name: Option<String>,
+}
+
+/// # TPM Device
+///
+/// Taken from TPM Device (Type 43).
+#[derive(Serialize, Debug)]
+pub struct TpmInventory {
+    /// TCG vendor ID
+    pub vendor_id: Option<String>,
+    /// TPM specification version (e.g. "2.0")
+    pub spec_version: Option<String>,
+    /// Description
+    pub description: Option<String>,
+}
+
+impl Inventory {
+    /// Builds an inventory from the given table
+    pub fn new(data: &SMBiosData) -> Self {
+        let system = data
+            .first::<SMBiosSystemInformation<'_>>()
+            .map(|system_info| SystemInventory {
+                manufacturer: non_empty(system_info.manufacturer()),
+                product_name: non_empty(system_info.product_name()),
+                version: non_empty(system_info.version()),
+                serial_number: non_empty(system_info.serial_number()),
+                uuid: system_info.uuid().map(|uuid| format!("{}", uuid)),
+                chassis_type: None,
+            })
+            .unwrap_or_default();
+        let system = SystemInventory {
+            chassis_type: data.find_map(|chassis: SMBiosSystemChassisInformation<'_>| {
+                chassis
+                    .chassis_type()
+                    .map(|chassis_type| format!("{}", chassis_type))
+            }),
+            ..system
+        };
+
+        let bios = data
+            .first::<SMBiosInformation<'_>>()
+            .map(|bios_info| BiosInventory {
+                vendor: non_empty(bios_info.vendor()),
+                version: non_empty(bios_info.version()),
+                release_date: non_empty(bios_info.release_date()),
+            })
+            .unwrap_or_default();
+
+        let processors = data
+            .map(
+                |processor: SMBiosProcessorInformation<'_>| ProcessorInventory {
+                    socket: non_empty(processor.socket_designation()),
+                    populated: processor
+                        .status()
+                        .map(|status| status.socket_populated())
+                        .unwrap_or(false),
+                    manufacturer: non_empty(processor.processor_manufacturer()),
+                    version: non_empty(processor.processor_version()),
+                    core_count: processor.effective_core_count(),
+                    thread_count: processor.effective_thread_count(),
+                    max_speed_mhz: match processor.max_speed() {
+                        Some(ProcessorSpeed::MHz(mhz)) => Some(mhz),
+                        _ => None,
+                    },
+                },
+            )
+            .collect();
+
+        let devices: Vec<MemoryDeviceInventory> = data
+            .map(|device: SMBiosMemoryDevice<'_>| MemoryDeviceInventory {
+                device_locator: non_empty(device.device_locator()),
+                bank_locator: non_empty(device.bank_locator()),
+                populated: !matches!(device.size(), Some(MemorySize::NotInstalled)),
+                size_bytes: device.effective_size(),
+                memory_type: device
+                    .memory_type()
+                    .map(|memory_type| format!("{}", memory_type)),
+                speed_mts: device.effective_speed(),
+                configured_speed_mts: device.effective_configured_speed(),
+                manufacturer: non_empty(device.manufacturer()),
+                part_number: non_empty(device.part_number()),
+            })
+            .collect();
+        let memory = MemoryInventory {
+            total_bytes: devices
+                .iter()
+                .filter(|device| device.populated)
+                .filter_map(|device| device.size_bytes)
+                .sum(),
+            devices,
+        };
+
+        let slots = data
+            .map(|slot: SMBiosSystemSlot<'_>| SlotInventory {
+                designation: non_empty(slot.slot_designation()),
+                slot_type: slot
+                    .system_slot_type()
+                    .map(|slot_type| format!("{}", slot_type)),
+                pci_express: matches!(
+                    slot.system_slot_type().map(|slot_type| slot_type.value),
+                    Some(SystemSlotType::PciExpress(_, _))
+                ),
+                in_use: matches!(
+                    slot.current_usage().map(|usage| usage.value),
+                    Some(SlotCurrentUsage::InUse)
+                ),
+            })
+            .collect();
+
+        let batteries = data
+            .map(|battery: SMBiosPortableBattery<'_>| BatteryInventory {
+                location: non_empty(battery.location()),
+                manufacturer: non_empty(battery.manufacturer()),
+                device_name: non_empty(battery.device_name()),
+            })
+            .collect();
+
+        let tpm = data.first::<SMBiosTpmDevice<'_>>().map(|tpm| TpmInventory {
+            vendor_id: tpm.vendor_id().map(|vendor_id| {
+                String::from_utf8_lossy(vendor_id.array)
+                    .trim_end_matches(char::from(0))
+                    .to_string()
+            }),
+            spec_version: match (tpm.major_spec_version(), tpm.minor_spec_version()) {
+                (Some(major), Some(minor)) => Some(format!("{}.{}", major, minor)),
+                _ => None,
+            },
+            description: non_empty(tpm.description()),
+        });
+
+        let configuration_options = data
+            .collect::<SMBiosSystemConfigurationOptions<'_>>()
+            .iter()
+            .flat_map(|options| options.configuration_options())
+            .collect();
+
+        Inventory {
+            system,
+            bios,
+            processors,
+            memory,
+            slots,
+            batteries,
+            tpm,
+            configuration_options,
+        }
+    }
+}
+
+fn non_empty(string: SMBiosString) -> Option<String> {
+    string
+        .to_utf8_lossy()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// Formats a byte count using the largest binary unit which divides it evenly
+// `u64::is_multiple_of` needs a newer compiler than this crate requires
+#[allow(clippy::manual_is_multiple_of)]
+fn fmt_bytes(bytes: u64) -> String {
+    const UNITS: [(u64, &str); 4] = [
+        (1 << 40, "TiB"),
+        (1 << 30, "GiB"),
+        (1 << 20, "MiB"),
+        (1 << 10, "KiB"),
+    ];
+    UNITS
+        .iter()
+        .find(|(size, _)| bytes >= *size && bytes % *size == 0)
+        .map(|(size, unit)| format!("{} {}", bytes / size, unit))
+        .unwrap_or_else(|| format!("{} bytes", bytes))
+}
+
+fn fmt_optional(value: &Option<String>) -> &str {
+    value.as_deref().unwrap_or("Unknown")
+}
+
+impl fmt::Display for Inventory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const INDENT: &str = "           ";
+
+        write!(
+            f,
+            "System:    {} {}",
+            fmt_optional(&self.system.manufacturer),
+            fmt_optional(&self.system.product_name)
+        )?;
+        if let Some(version) = &self.system.version {
+            write!(f, " {}", version)?;
+        }
+        if let Some(chassis_type) = &self.system.chassis_type {
+            write!(f, " ({})", chassis_type)?;
+        }
+        writeln!(f)?;
+        writeln!(f, "Serial:    {}", fmt_optional(&self.system.serial_number))?;
+        writeln!(f, "UUID:      {}", fmt_optional(&self.system.uuid))?;
+        writeln!(
+            f,
+            "BIOS:      {} {} ({})",
+            fmt_optional(&self.bios.vendor),
+            fmt_optional(&self.bios.version),
+            fmt_optional(&self.bios.release_date)
+        )?;
+
+        for (index, processor) in self.processors.iter().enumerate() {
+            let label = if index == 0 { "Processor: " } else { INDENT };
+            write!(f, "{}{}: ", label, fmt_optional(&processor.socket))?;
+            if !processor.populated {
+                writeln!(f, "empty")?;
+                continue;
+            }
+            write!(f, "{}", fmt_optional(&processor.version))?;
+            if let Some(core_count) = processor.core_count {
+                write!(f, ", {} cores", core_count)?;
+            }
+            if let Some(thread_count) = processor.thread_count {
+                write!(f, ", {} threads", thread_count)?;
+            }
+            if let Some(max_speed) = processor.max_speed_mhz {
+                write!(f, ", max {} MHz", max_speed)?;
+            }
+            writeln!(f)?;
+        }
+
+        let populated = self
+            .memory
+            .devices
+            .iter()
+            .filter(|device| device.populated)
+            .count();
+        writeln!(
+            f,
+            "Memory:    {} total, {} of {} slots populated",
+            fmt_bytes(self.memory.total_bytes),
+            populated,
+            self.memory.devices.len()
+        )?;
+        for device in self.memory.devices.iter() {
+            write!(f, "{}{}", INDENT, fmt_optional(&device.device_locator))?;
+            if let Some(bank_locator) = &device.bank_locator {
+                write!(f, "/{}", bank_locator)?;
+            }
+            if !device.populated {
+                writeln!(f, ": empty")?;
+                continue;
+            }
+            write!(
+                f,
+                ": {}",
+                device
+                    .size_bytes
+                    .map(fmt_bytes)
+                    .unwrap_or_else(|| "Unknown size".to_string())
+            )?;
+            if let Some(memory_type) = &device.memory_type {
+                write!(f, " {}", memory_type)?;
+            }
+            if let Some(speed) = device.speed_mts {
+                write!(f, " {} MT/s", speed)?;
+            }
+            if let Some(configured_speed) = device.configured_speed_mts {
+                write!(f, " (configured {} MT/s)", configured_speed)?;
+            }
+            if let Some(manufacturer) = &device.manufacturer {
+                write!(f, " {}", manufacturer)?;
+            }
+            if let Some(part_number) = &device.part_number {
+                write!(f, " {}", part_number)?;
+            }
+            writeln!(f)?;
+        }
+
+        let pci_express_slots = self.slots.iter().filter(|slot| slot.pci_express);
+        writeln!(
+            f,
+            "PCIe:      {} of {} slots populated",
+            pci_express_slots.clone().filter(|slot| slot.in_use).count(),
+            pci_express_slots.clone().count()
+        )?;
+        for slot in pci_express_slots {
+            writeln!(
+                f,
+                "{}{}: {} {}",
+                INDENT,
+                fmt_optional(&slot.designation),
+                fmt_optional(&slot.slot_type),
+                if slot.in_use { "in use" } else { "empty" }
+            )?;
+        }
+
+        match self.batteries.first() {
+            Some(battery) => writeln!(
+                f,
+                "Battery:   {} {}",
+                fmt_optional(&battery.manufacturer),
+                fmt_optional(&battery.device_name)
+            )?,
+            None => writeln!(f, "Battery:   none")?,
+        }
+
+        match &self.tpm {
+            Some(tpm) => writeln!(
+                f,
+                "TPM:       {} version {}",
+                fmt_optional(&tpm.vendor_id),
+                fmt_optional(&tpm.spec_version)
+            )?,
+            None => writeln!(f, "TPM:       none")?,
+        }
+
+        for (index, option) in self.configuration_options.iter().enumerate() {
+            let label = if index == 0 { "Options:   " } else { INDENT };
+            writeln!(f, "{}{}", label, option)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_inventory() {
+        let mut path = PathBuf::new();
+        path.push(".");
+        path.push("tests");
+        path.push("jeffgerlap_3_2_0");
+        path.set_extension("dat");
+
+        let data = crate::load_smbios_data_from_file(&path).unwrap();
+        let inventory = Inventory::new(&data);
+
+        assert_eq!(
+            inventory.system.product_name,
+            Some("Surface Laptop 3".to_string())
+        );
+        assert_eq!(inventory.processors.len(), 1);
+        assert!(inventory.processors[0].populated);
+        assert_eq!(inventory.processors[0].core_count, Some(4));
+        assert_eq!(inventory.processors[0].thread_count, Some(8));
+        assert_eq!(inventory.memory.devices.len(), 2);
+        assert_eq!(inventory.memory.total_bytes, 16 * 1024 * 1024 * 1024);
+        assert_eq!(inventory.memory.devices[0].speed_mts, Some(2400));
+        assert!(inventory.slots.is_empty());
+        assert!(inventory.tpm.is_none());
+
+        let text = format!("{}", inventory);
+        assert!(text.contains("Memory:    16 GiB total, 2 of 2 slots populated\n"));
+        assert!(text.contains("PCIe:      0 of 0 slots populated\n"));
+        assert!(inventory.configuration_options.is_empty());
+        assert!(!text.contains("Options:"));
+    }
+
+    #[test]
+    fn test_configuration_options() {
+        let mut table = vec![0x0C, 0x05, 0x23, 0x00, 0x02];
+        table.extend_from_slice(b"JP1: 1-2 Closed\0SW2: Off\0\0");
+        let data = SMBiosData::from_vec_and_version(table, None);
+        let inventory = Inventory::new(&data);
+
+        assert_eq!(inventory.configuration_options.len(), 2);
+        assert_eq!(
+            inventory.configuration_options[1].designator.as_deref(),
+            Some("SW2")
+        );
+        let text = format!("{}", inventory);
+        assert!(text.ends_with("Options:   JP1: 1-2 Closed\n           SW2: Off\n"));
+    }
+
+    #[test]
+    fn test_fmt_bytes() {
+        assert_eq!(fmt_bytes(0), "0 bytes");
+        assert_eq!(fmt_bytes(512 * 1024), "512 KiB");
+        assert_eq!(fmt_bytes(8 * 1024 * 1024 * 1024), "8 GiB");
+        assert_eq!(fmt_bytes(1536 * 1024 * 1024), "1536 MiB");
+    }
+}
@@ -8,17 +8,46 @@
 #![warn(missing_docs)]
 #![deny(rust_2018_idioms)]
 
+mod convert;
 mod core;
+mod diff;
+mod dot;
+mod event_log;
+mod field_annotations;
 mod file_io;
+mod handle_graph;
+mod hex_dump;
+mod inventory;
 mod macos;
+mod memory_topology;
+mod oem_string_map;
+mod processor_topology;
+mod redact;
+mod string_properties;
+mod struct_identity;
 mod structs;
 mod unix;
+mod validate;
 mod windows;
 
 pub use structs::*;
 
 pub use crate::core::*;
+pub use convert::*;
+pub use diff::*;
+pub use dot::*;
+pub use event_log::*;
+pub use field_annotations::*;
 pub use file_io::*;
+pub use handle_graph::*;
+pub use hex_dump::*;
+pub use inventory::*;
+pub use memory_topology::*;
+pub use oem_string_map::*;
+pub use processor_topology::*;
+pub use redact::*;
+pub use struct_identity::*;
+pub use validate::*;
 
 #[cfg(target_family = "windows")]
 pub use windows::{load_windows_smbios_data, raw_smbios_from_device, table_load_from_device};
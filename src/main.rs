@@ -1,4 +1,4 @@
-use std::{error::Error, fmt::Display, path::Path};
+use std::{error::Error, fmt::Display, io, path::Path, process};
 
 use smbioslib::*;
 
@@ -41,13 +41,130 @@ impl Error for BiosParseError {
 
 impl Display for BiosParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // Here we can match and turn each arm into a human readable statement.
-        // We have other variants to add so we will wait before doing so.
-        write!(f, "{:?}", &self)
+        let message = match self {
+            BiosParseError::BiosVendorNotFound => "BIOS vendor not found",
+            BiosParseError::BiosVersionNotFound => "BIOS version not found",
+            BiosParseError::BiosReleaseDateNotFound => "BIOS release date not found",
+            BiosParseError::BiosRevisionNotFound => "BIOS revision not found",
+            BiosParseError::FirmewareRevisionNotFound => "firmware revision not found",
+            BiosParseError::SystemManufacturerNotFound => "system manufacturer not found",
+            BiosParseError::SystemProductNameNotFound => "system product name not found",
+            BiosParseError::SystemVersionNotFound => "system version not found",
+            BiosParseError::SystemSerialNumberNotFound => "system serial number not found",
+            BiosParseError::SystemUuidNotFound => "system UUID not found",
+            BiosParseError::SystemSkuNumberNotFound => "system SKU number not found",
+            BiosParseError::SystemFamilyNotFound => "system family not found",
+            BiosParseError::BaseboardManufacturerNotFound => "baseboard manufacturer not found",
+            BiosParseError::BaseboardProductNameNotFound => "baseboard product name not found",
+            BiosParseError::BaseboardVersionNotFound => "baseboard version not found",
+            BiosParseError::BaseboardSerialNumberNotFound => "baseboard serial number not found",
+            BiosParseError::BaseboardAssetTagNotFound => "baseboard asset tag not found",
+            BiosParseError::ChassisManufacturerNotFound => "chassis manufacturer not found",
+            BiosParseError::ChassisTypeNotFound => "chassis type not found",
+            BiosParseError::ChassisVersionNotFound => "chassis version not found",
+            BiosParseError::ChassisSerialNumberNotFound => "chassis serial number not found",
+            BiosParseError::ChassisAssetTagNotFound => "chassis asset tag not found",
+            BiosParseError::ProcessorFamilyNotFound => "processor family not found",
+            BiosParseError::ProcessorManufacturerNotFound => "processor manufacturer not found",
+            BiosParseError::ProcessorVersionNotFound => "processor version not found",
+            BiosParseError::ProcessorFrequencyNotFound => "processor frequency not found",
+            BiosParseError::InvalidKeywordOnCommandLine => "invalid keyword",
+        };
+        write!(f, "{}", message)
     }
 }
 
-fn string_keyword(keyword: String, data: &SMBiosData) -> Result<String, BiosParseError> {
+/// Errors reported by smbiosdump, each mapped to a distinct exit code
+/// so that scripts can react to the cause of a failure.
+#[derive(Debug)]
+enum CliError {
+    /// The command line could not be understood (exit code 2)
+    BadArguments(String),
+    /// A file, table or requested value does not exist (exit code 3)
+    NotFound(String),
+    /// The table or a file could not be accessed (exit code 4)
+    PermissionDenied(String),
+    /// The table could not be parsed (exit code 5)
+    CorruptTable(String),
+    /// Validation found problems at or above the requested severity (exit code 6)
+    ValidationFailed(String),
+    /// Any other failure (exit code 1)
+    Failure(String),
+}
+
+impl CliError {
+    const EXIT_FAILURE: i32 = 1;
+    const EXIT_BAD_ARGUMENTS: i32 = 2;
+    const EXIT_NOT_FOUND: i32 = 3;
+    const EXIT_PERMISSION_DENIED: i32 = 4;
+    const EXIT_CORRUPT_TABLE: i32 = 5;
+    const EXIT_VALIDATION_FAILED: i32 = 6;
+
+    fn exit_code(&self) -> i32 {
+        match self {
+            CliError::BadArguments(_) => Self::EXIT_BAD_ARGUMENTS,
+            CliError::NotFound(_) => Self::EXIT_NOT_FOUND,
+            CliError::PermissionDenied(_) => Self::EXIT_PERMISSION_DENIED,
+            CliError::CorruptTable(_) => Self::EXIT_CORRUPT_TABLE,
+            CliError::ValidationFailed(_) => Self::EXIT_VALIDATION_FAILED,
+            CliError::Failure(_) => Self::EXIT_FAILURE,
+        }
+    }
+
+    /// Classifies an I/O error, prefixing its message with what was being accessed
+    fn from_io(context: &str, error: io::Error) -> Self {
+        let message = format!("{}: {}", context, error);
+        match error.kind() {
+            io::ErrorKind::NotFound => CliError::NotFound(message),
+            io::ErrorKind::PermissionDenied => CliError::PermissionDenied(message),
+            io::ErrorKind::InvalidData | io::ErrorKind::UnexpectedEof => {
+                CliError::CorruptTable(message)
+            }
+            io::ErrorKind::InvalidInput => CliError::BadArguments(message),
+            _ => CliError::Failure(message),
+        }
+    }
+}
+
+impl Error for CliError {}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::BadArguments(message)
+            | CliError::NotFound(message)
+            | CliError::PermissionDenied(message)
+            | CliError::CorruptTable(message)
+            | CliError::ValidationFailed(message)
+            | CliError::Failure(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl From<getopts::Fail> for CliError {
+    fn from(error: getopts::Fail) -> Self {
+        CliError::BadArguments(error.to_string())
+    }
+}
+
+impl From<BiosParseError> for CliError {
+    fn from(error: BiosParseError) -> Self {
+        match error {
+            BiosParseError::InvalidKeywordOnCommandLine => {
+                CliError::BadArguments(error.to_string())
+            }
+            _ => CliError::NotFound(error.to_string()),
+        }
+    }
+}
+
+impl From<serde_json::Error> for CliError {
+    fn from(error: serde_json::Error) -> Self {
+        CliError::Failure(format!("json: {}", error))
+    }
+}
+
+fn string_keyword(keyword: &str, data: &SMBiosData) -> Result<String, BiosParseError> {
     match keyword.to_lowercase().as_str() {
         "bios-vendor" => data
             .find_map(|bios_info: SMBiosInformation| bios_info.vendor().to_utf8_lossy())
@@ -199,65 +316,536 @@ fn string_keyword(keyword: String, data: &SMBiosData) -> Result<String, BiosPars
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let file_option = "f";
+const USAGE: &str = "Usage: smbiosdump [COMMAND] [OPTIONS]
+
+Commands:
+    dump       print the table (the default when no command is given)
+    get        print a single value identified by KEYWORD
+    diff       compare two tables
+    convert    convert a table between dump formats
+    validate   check the table for conformance with the SMBIOS specification
+    summary    print a one-screen system summary
+
+Run 'smbiosdump COMMAND --help' for the options of a command.
+The table is read from the device unless -f FILE is given.
+
+Exit codes:
+    0    success
+    1    other failure
+    2    bad arguments
+    3    not found (file, table or requested value)
+    4    permission denied
+    5    corrupt table
+    6    validation findings at or above the --fail-on severity
+";
+
+const KEYWORDS: [&str; 26] = [
+    "bios-vendor",
+    "bios-version",
+    "bios-release-date",
+    "bios-revision",
+    "firmware-revision",
+    "system-manufacturer",
+    "system-product-name",
+    "system-version",
+    "system-serial-number",
+    "system-uuid",
+    "system-sku-number",
+    "system-family",
+    "baseboard-manufacturer",
+    "baseboard-product-name",
+    "baseboard-version",
+    "baseboard-serial-number",
+    "baseboard-asset-tag",
+    "chassis-manufacturer",
+    "chassis-type",
+    "chassis-version",
+    "chassis-serial-number",
+    "chassis-asset-tag",
+    "processor-family",
+    "processor-manufacturer",
+    "processor-version",
+    "processor-frequency",
+];
+
+const FILE_OPTION: &str = "f";
+const JSON_OPTION: &str = "j";
+const HELP_OPTION: &str = "h";
+
+/// Options shared by every command
+fn command_options() -> getopts::Options {
+    let mut opts = getopts::Options::new();
+    opts.optflag(HELP_OPTION, "help", "print this help");
+    opts
+}
+
+/// Parses the arguments of a command, returning `None` after printing
+/// help when it was requested
+fn parse_command(
+    opts: &getopts::Options,
+    args: &[String],
+    brief: &str,
+) -> Result<Option<getopts::Matches>, CliError> {
+    let matches = opts.parse(args)?;
+    if matches.opt_present(HELP_OPTION) {
+        print!("{}", opts.usage(brief));
+        return Ok(None);
+    }
+    Ok(Some(matches))
+}
+
+/// Loads the table from -f FILE, or from the device when no file was given
+fn load_table(matches: &getopts::Matches) -> Result<SMBiosData, CliError> {
+    let (source, data) = match matches.opt_str(FILE_OPTION) {
+        Some(filename) => {
+            let data = load_smbios_data_from_file(Path::new(&filename))
+                .map_err(|error| CliError::from_io(&filename, error))?;
+            (filename, data)
+        }
+        None => {
+            let data =
+                table_load_from_device().map_err(|error| CliError::from_io("device", error))?;
+            ("device".to_string(), data)
+        }
+    };
+    check_table(&data)
+        .map_err(|message| CliError::CorruptTable(format!("{}: {}", source, message)))?;
+    Ok(data)
+}
+
+/// Checks that the table holds structures and ends with an End-of-Table structure
+///
+/// The loaders stop quietly at the first structure which runs past the end
+/// of the data, so a truncated table or bytes which are not a table at all
+/// load as a table missing some or all of its structures.
+fn check_table(data: &SMBiosData) -> Result<(), String> {
+    let last = match data.iter().last() {
+        Some(last) => last,
+        None => return Err("no SMBIOS structures found".to_string()),
+    };
+    if last.fields.len() < last.header.length() as usize {
+        return Err(format!(
+            "the structure with handle {:#06X} is truncated",
+            last.header.handle().0
+        ));
+    }
+    if !data
+        .iter()
+        .any(|parts| parts.header.struct_type() == SMBiosEndOfTable::STRUCT_TYPE)
+    {
+        return Err(
+            "no End-of-Table structure (Type 127) found, the table may be truncated".to_string(),
+        );
+    }
+    Ok(())
+}
+
+fn dump_command(args: &[String]) -> Result<(), CliError> {
     let output_option = "o";
+    let hex_option = "hex";
+    let graph_option = "graph";
+    let redact_option = "redact";
     let string_option = "s";
-    let json_option = "j";
+    let dump_option = "dump";
+    let summary_option = "summary";
 
-    let args: Vec<String> = std::env::args().collect();
-    let mut opts = getopts::Options::new();
-    opts.optopt(file_option, "", "read smbios table from file", "FILE");
-    opts.optopt(output_option, "", "dump smbios table to a file", "FILE");
+    let mut opts = command_options();
+    opts.optopt(FILE_OPTION, "", "read smbios table from file", "FILE");
     opts.optopt(
         string_option,
-        "",
-        "Only display the value of the DMI string identified by KEYWORD.",
+        "string",
+        "print a single value identified by KEYWORD, the same as 'get KEYWORD'",
         "KEYWORD",
     );
-    opts.optflag(json_option, "", "output in json format");
+    opts.optopt(
+        output_option,
+        "",
+        "write the raw smbios table to a file",
+        "FILE",
+    );
+    opts.optflag(JSON_OPTION, "", "output in json format");
+    opts.optflag(
+        "",
+        hex_option,
+        "print the raw bytes and strings of each structure in hex",
+    );
+    opts.optflag("", dump_option, "the same as --hex");
+    opts.optflag(
+        "",
+        graph_option,
+        "print the references between structures as a Graphviz DOT graph",
+    );
+    opts.optflag(
+        "",
+        redact_option,
+        "hash serial numbers, asset tags and the system UUID (requires -o)",
+    );
+    opts.optflag(
+        "",
+        summary_option,
+        "print a one-screen system summary, the same as 'summary'",
+    );
 
-    let matches = opts.parse(&args[1..])?;
+    let matches = match parse_command(&opts, args, "Usage: smbiosdump dump [OPTIONS]")? {
+        Some(matches) => matches,
+        None => return Ok(()),
+    };
+    if !matches.free.is_empty() {
+        return Err(CliError::BadArguments(format!(
+            "unexpected argument '{}'",
+            matches.free[0]
+        )));
+    }
 
-    if !matches.opt_present(file_option)
-        && !matches.opt_present(output_option)
-        && !matches.opt_present(string_option)
-        && !matches.opt_present(json_option)
-    {
-        println!("table_data: {:#?}", table_load_from_device()?);
-        return Ok(());
+    if let Some(keyword) = matches.opt_str(string_option) {
+        return print_keyword(&keyword, &matches);
     }
 
-    match matches.opt_str(file_option) {
-        Some(filename) => {
-            let file_path = Path::new(&filename);
-            println!("{:#?}", load_smbios_data_from_file(&file_path)?);
-        }
-        None => (),
+    if matches.opt_present(summary_option) {
+        return print_summary(&matches);
     }
 
-    match matches.opt_str(output_option) {
-        Some(filename) => {
-            let out_path = Path::new(&filename);
-            dump_raw(raw_smbios_from_device()?, &out_path)?;
+    if let Some(out_filename) = matches.opt_str(output_option) {
+        let out_path = Path::new(&out_filename);
+        let raw_data = if matches.opt_present(redact_option) || matches.opt_present(FILE_OPTION) {
+            let mut smbios_data = load_table(&matches)?;
+            if matches.opt_present(redact_option) {
+                smbios_data.redact(&RedactionPolicy::default());
+            }
+            smbios_data.to_bytes()
+        } else {
+            raw_smbios_from_device().map_err(|error| CliError::from_io("device", error))?
+        };
+        return dump_raw(raw_data, out_path)
+            .map_err(|error| CliError::from_io(&out_filename, error));
+    }
+
+    if matches.opt_present(redact_option) {
+        return Err(CliError::BadArguments(
+            "--redact requires an output file (-o FILE)".to_string(),
+        ));
+    }
+
+    let smbios_data = load_table(&matches)?;
+    if matches.opt_present(JSON_OPTION) {
+        println!("{}", serde_json::to_string(&smbios_data)?);
+    } else if matches.opt_present(hex_option) || matches.opt_present(dump_option) {
+        print!("{}", smbios_data.hex_dump());
+    } else if matches.opt_present(graph_option) {
+        print!("{}", smbios_data.dot_graph());
+    } else {
+        println!("{:#?}", smbios_data);
+    }
+
+    Ok(())
+}
+
+fn get_command(args: &[String]) -> Result<(), CliError> {
+    let mut opts = command_options();
+    opts.optopt(FILE_OPTION, "", "read smbios table from file", "FILE");
+
+    let brief = format!(
+        "Usage: smbiosdump get [OPTIONS] KEYWORD\n\nKeywords:\n    {}",
+        KEYWORDS.join("\n    ")
+    );
+    let matches = match parse_command(&opts, args, &brief)? {
+        Some(matches) => matches,
+        None => return Ok(()),
+    };
+    let keyword = match matches.free.as_slice() {
+        [keyword] => keyword,
+        _ => {
+            return Err(CliError::BadArguments(
+                "expected exactly one KEYWORD".to_string(),
+            ))
         }
-        None => (),
+    };
+    print_keyword(keyword, &matches)
+}
+
+/// Prints the value identified by a keyword of the get command
+fn print_keyword(keyword: &str, matches: &getopts::Matches) -> Result<(), CliError> {
+    if !KEYWORDS.contains(&keyword.to_lowercase().as_str()) {
+        return Err(CliError::BadArguments(format!(
+            "invalid keyword '{}'",
+            keyword
+        )));
     }
 
-    match matches.opt_str(string_option) {
-        Some(keyword) => {
-            let smbios_data = table_load_from_device()?;
-            let output = string_keyword(keyword, &smbios_data)?;
-            println!("{}", output);
+    let smbios_data = load_table(matches)?;
+    println!("{}", string_keyword(keyword, &smbios_data)?);
+
+    Ok(())
+}
+
+fn diff_command(args: &[String]) -> Result<(), CliError> {
+    let mut opts = command_options();
+    opts.optflag(JSON_OPTION, "", "output in json format");
+
+    let matches = match parse_command(&opts, args, "Usage: smbiosdump diff [OPTIONS] OLD NEW")? {
+        Some(matches) => matches,
+        None => return Ok(()),
+    };
+    let (old_filename, new_filename) = match matches.free.as_slice() {
+        [old_filename, new_filename] => (old_filename, new_filename),
+        _ => {
+            return Err(CliError::BadArguments(
+                "expected two table files, OLD and NEW".to_string(),
+            ))
         }
-        None => (),
+    };
+
+    let old_data = load_smbios_data_from_file(Path::new(old_filename))
+        .map_err(|error| CliError::from_io(old_filename, error))?;
+    let new_data = load_smbios_data_from_file(Path::new(new_filename))
+        .map_err(|error| CliError::from_io(new_filename, error))?;
+    let result = diff(&old_data, &new_data);
+
+    if matches.opt_present(JSON_OPTION) {
+        println!("{}", serde_json::to_string(&result)?);
+    } else {
+        print!("{}", result);
     }
-    if matches.opt_present(json_option) {
-        let smbios_data = table_load_from_device()?;
-        if let Ok(output) = serde_json::to_string(&smbios_data) {
-            println!("{}", output)
+
+    Ok(())
+}
+
+fn convert_command(args: &[String]) -> Result<(), CliError> {
+    let from_option = "from";
+    let to_option = "to";
+    let force_option = "force";
+
+    // JSON holds decoded values which cannot be converted back to a table
+    let format_names = |include_json: bool| {
+        TableFormat::ALL
+            .iter()
+            .filter(|format| include_json || **format != TableFormat::Json)
+            .map(|format| format.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let formats = format_names(true);
+    let input_formats = format_names(false);
+
+    let mut opts = command_options();
+    opts.optopt(
+        "",
+        from_option,
+        &format!("format of INPUT, detected when omitted ({})", input_formats),
+        "FORMAT",
+    );
+    opts.optopt(
+        "",
+        to_option,
+        &format!("format of OUTPUT ({})", formats),
+        "FORMAT",
+    );
+    opts.optflag(
+        "",
+        force_option,
+        "convert even if information such as the SMBIOS version would be lost",
+    );
+
+    let matches = match parse_command(
+        &opts,
+        args,
+        "Usage: smbiosdump convert [OPTIONS] --to FORMAT INPUT OUTPUT\n\n\
+         JSON can be written but not read, as it holds decoded values rather than the table.",
+    )? {
+        Some(matches) => matches,
+        None => return Ok(()),
+    };
+    let (in_filename, out_filename) = match matches.free.as_slice() {
+        [in_filename, out_filename] => (in_filename, out_filename),
+        _ => {
+            return Err(CliError::BadArguments(
+                "expected an INPUT and an OUTPUT file".to_string(),
+            ))
         }
+    };
+    let parse_format = |format: String| {
+        format
+            .parse::<TableFormat>()
+            .map_err(|error| CliError::BadArguments(error.to_string()))
+    };
+    let from = matches.opt_str(from_option).map(parse_format).transpose()?;
+    let to = match matches.opt_str(to_option) {
+        Some(format) => parse_format(format)?,
+        None => {
+            return Err(CliError::BadArguments(
+                "--to FORMAT is required".to_string(),
+            ))
+        }
+    };
+
+    let input =
+        std::fs::read(in_filename).map_err(|error| CliError::from_io(in_filename, error))?;
+    let from = from.unwrap_or_else(|| TableFormat::detect(&input));
+    if from == TableFormat::Json {
+        return Err(CliError::BadArguments(format!(
+            "cannot convert from json, INPUT must be one of: {}",
+            input_formats
+        )));
+    }
+    let output = convert_table(input, Some(from), to, matches.opt_present(force_option))
+        .map_err(|error| CliError::from_io(in_filename, error))?;
+    dump_raw(output, Path::new(out_filename))
+        .map_err(|error| CliError::from_io(out_filename, error))
+}
+
+fn validate_command(args: &[String]) -> Result<(), CliError> {
+    let fail_on_option = "fail-on";
+
+    let mut opts = command_options();
+    opts.optopt(FILE_OPTION, "", "read smbios table from file", "FILE");
+    opts.optflag(JSON_OPTION, "", "output in json format");
+    opts.optopt(
+        "",
+        fail_on_option,
+        "exit with code 6 when a finding is at or above SEVERITY (info, warning, error; default error)",
+        "SEVERITY",
+    );
+
+    let matches = match parse_command(&opts, args, "Usage: smbiosdump validate [OPTIONS]")? {
+        Some(matches) => matches,
+        None => return Ok(()),
+    };
+    let threshold = match matches.opt_str(fail_on_option) {
+        Some(severity) => severity
+            .parse::<Severity>()
+            .map_err(|error| CliError::BadArguments(error.to_string()))?,
+        None => Severity::Error,
+    };
+
+    let report = validate(&load_table(&matches)?);
+    if matches.opt_present(JSON_OPTION) {
+        println!("{}", serde_json::to_string(&report)?);
+    } else {
+        print!("{}", report);
+    }
+
+    if report.fails(threshold) {
+        return Err(CliError::ValidationFailed(format!(
+            "validation found findings at or above severity {}",
+            threshold
+        )));
+    }
+    Ok(())
+}
+
+fn summary_command(args: &[String]) -> Result<(), CliError> {
+    let mut opts = command_options();
+    opts.optopt(FILE_OPTION, "", "read smbios table from file", "FILE");
+    opts.optflag(JSON_OPTION, "", "output in json format");
+
+    let matches = match parse_command(&opts, args, "Usage: smbiosdump summary [OPTIONS]")? {
+        Some(matches) => matches,
+        None => return Ok(()),
+    };
+
+    print_summary(&matches)
+}
+
+/// Prints the summary of the table given by the -f and -j options of `matches`
+fn print_summary(matches: &getopts::Matches) -> Result<(), CliError> {
+    let inventory = Inventory::new(&load_table(matches)?);
+    if matches.opt_present(JSON_OPTION) {
+        println!("{}", serde_json::to_string(&inventory)?);
+    } else {
+        print!("{}", inventory);
     }
 
     Ok(())
 }
+
+fn run(args: &[String]) -> Result<(), CliError> {
+    if let Some("help") | Some("--help") | Some("-h") = args.first().map(String::as_str) {
+        print!("{}", USAGE);
+        return Ok(());
+    }
+
+    // Options without a command are passed to the default command, "dump"
+    let (command, command_args) = match args.split_first() {
+        Some((command, command_args)) if !command.starts_with('-') => {
+            (command.as_str(), command_args)
+        }
+        _ => ("dump", args),
+    };
+
+    match command {
+        "dump" => dump_command(command_args),
+        "get" => get_command(command_args),
+        "diff" => diff_command(command_args),
+        "convert" => convert_command(command_args),
+        "validate" => validate_command(command_args),
+        "summary" => summary_command(command_args),
+        _ => Err(CliError::BadArguments(format!(
+            "unknown command '{}'",
+            command
+        ))),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if let Err(error) = run(&args[1..]) {
+        eprintln!("smbiosdump: {}", error);
+        if let CliError::BadArguments(_) = error {
+            eprintln!("Run 'smbiosdump --help' for usage.");
+        }
+        process::exit(error.exit_code());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jeffgerlap_table() -> Vec<u8> {
+        load_smbios_data_from_file(Path::new("tests/jeffgerlap_3_2_0.dat"))
+            .unwrap()
+            .to_bytes()
+    }
+
+    #[test]
+    fn test_check_table() {
+        let table = jeffgerlap_table();
+        assert_eq!(
+            check_table(&SMBiosData::from_vec_and_version(table, None)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_check_table_empty() {
+        assert_eq!(
+            check_table(&SMBiosData::from_vec_and_version(Vec::new(), None)),
+            Err("no SMBIOS structures found".to_string())
+        );
+    }
+
+    #[test]
+    fn test_check_table_truncated() {
+        let mut table = jeffgerlap_table();
+        table.truncate(37);
+        assert!(check_table(&SMBiosData::from_vec_and_version(table, None)).is_err());
+
+        let mut table = jeffgerlap_table();
+        table.pop();
+        assert!(check_table(&SMBiosData::from_vec_and_version(table, None)).is_err());
+    }
+
+    #[test]
+    fn test_check_table_random_bytes() {
+        let mut seed = 0x2545_F491u32;
+        let bytes = (0..100)
+            .map(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 17;
+                seed ^= seed << 5;
+                seed as u8
+            })
+            .collect();
+        assert!(check_table(&SMBiosData::from_vec_and_version(bytes, None)).is_err());
+    }
+}
@@ -0,0 +1,370 @@
+//! Physical memory topology joined from Types 16, 17, 19 and 20.
+//!
+//! [MemoryTopology] groups each Physical Memory Array (Type 16) with its
+//! Memory Devices (Type 17) and the address ranges mapped to them by
+//! Memory Array Mapped Address (Type 19) and Memory Device Mapped Address
+//! (Type 20) structures. Addresses are normalized to byte addresses,
+//! whether the table gives them in kilobytes or in the extended fields.
+use crate::core::{Handle, SMBiosData};
+use crate::structs::*;
+use serde::Serialize;
+
+/// Starting address value meaning the range is given by the extended address fields
+const SEE_EXTENDED_ADDRESS: u32 = 0xFFFF_FFFF;
+
+/// # Physical Address Range
+///
+/// An inclusive range of byte addresses.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct AddressRange {
+    /// Address of the first byte of the range
+    pub start: u64,
+    /// Address of the last byte of the range
+    pub end: u64,
+}
+
+impl AddressRange {
+    /// Builds a range from the starting and ending address fields shared by Types 19 and 20
+    ///
+    /// `starting_address` and `ending_address` are in kilobytes, `ending_address`
+    /// naming the last kilobyte of the range. When `starting_address` is
+    /// FFFF FFFFh the range is given in bytes by the extended fields.
+    fn from_fields(
+        starting_address: Option<u32>,
+        ending_address: Option<u32>,
+        extended_starting_address: Option<u64>,
+        extended_ending_address: Option<u64>,
+    ) -> Option<Self> {
+        let range = match (starting_address?, ending_address?) {
+            (SEE_EXTENDED_ADDRESS, _) => AddressRange {
+                start: extended_starting_address?,
+                end: extended_ending_address?,
+            },
+            (start, end) => AddressRange {
+                start: (start as u64) << 10,
+                end: ((end as u64) << 10) | 0x3FF,
+            },
+        };
+        if range.end < range.start {
+            return None;
+        }
+        Some(range)
+    }
+
+    /// `true` if the address lies within the range
+    pub fn contains(&self, address: u64) -> bool {
+        self.start <= address && address <= self.end
+    }
+
+    /// Number of bytes in the range
+    ///
+    /// `None` if `end` is before `start`, or if the range covers the whole
+    /// 64-bit address space as 2^64 bytes do not fit in a `u64`.
+    pub fn byte_count(&self) -> Option<u64> {
+        self.end.checked_sub(self.start)?.checked_add(1)
+    }
+}
+
+/// # Memory Device Address Range
+///
+/// An address range mapped to a memory device by a Memory Device Mapped
+/// Address (Type 20) structure.
+#[derive(Serialize, Debug, Clone, Copy)]
+pub struct DeviceAddressRange {
+    /// Handle of the Memory Device Mapped Address structure
+    pub handle: Handle,
+    /// Byte addresses mapped to the device
+    pub range: AddressRange,
+    /// Handle of the Memory Array Mapped Address structure containing the range
+    pub memory_array_mapped_address_handle: Option<Handle>,
+    /// Position of the device in an interleave, 0 if not interleaved
+    pub interleave_position: Option<u8>,
+    /// Number of consecutive rows accessed from the device in an interleave
+    pub interleaved_data_depth: Option<u8>,
+}
+
+/// # Memory Device Topology
+#[derive(Serialize, Debug)]
+pub struct MemoryDeviceTopology {
+    /// Handle of the Memory Device structure
+    pub handle: Handle,
+    /// Device locator, e.g. "DIMM 0"
+    pub device_locator: Option<String>,
+    /// Bank locator, e.g. "P0 CHANNEL A"
+    pub bank_locator: Option<String>,
+    /// `true` if a memory device is installed in the socket
+    pub populated: bool,
+    /// Size in bytes, if known
+    pub size_bytes: Option<u64>,
+    /// Address ranges mapped to the device
+    pub address_ranges: Vec<DeviceAddressRange>,
+}
+
+/// # Memory Array Topology
+#[derive(Serialize, Debug)]
+pub struct MemoryArrayTopology {
+    /// Handle of the Physical Memory Array structure
+    pub handle: Handle,
+    /// Function of the array, e.g. "SystemMemory"
+    pub usage: Option<String>,
+    /// Maximum capacity of the array in bytes, if known
+    pub maximum_capacity_bytes: Option<u64>,
+    /// Number of slots or sockets for memory devices in the array
+    pub slot_count: Option<u16>,
+    /// Memory devices belonging to the array
+    pub devices: Vec<MemoryDeviceTopology>,
+    /// Address ranges mapped to the array by Memory Array Mapped Address structures
+    pub address_ranges: Vec<AddressRange>,
+}
+
+impl MemoryArrayTopology {
+    /// Number of memory devices installed in the array
+    pub fn populated_slots(&self) -> usize {
+        self.devices
+            .iter()
+            .filter(|device| device.populated)
+            .count()
+    }
+
+    /// Number of sockets without a memory device
+    ///
+    /// Uses the array's slot count when given, otherwise the number of
+    /// Memory Device structures describing empty sockets.
+    pub fn empty_slots(&self) -> usize {
+        match self.slot_count {
+            Some(slot_count) => (slot_count as usize).saturating_sub(self.populated_slots()),
+            None => self.devices.len() - self.populated_slots(),
+        }
+    }
+
+    /// Total size in bytes of the installed devices with a known size
+    pub fn installed_bytes(&self) -> u64 {
+        installed_bytes(self.devices.iter())
+    }
+}
+
+/// # Memory Topology
+#[derive(Serialize, Debug)]
+pub struct MemoryTopology {
+    /// Physical memory arrays with their devices and address ranges
+    pub arrays: Vec<MemoryArrayTopology>,
+    /// Memory devices which do not name a physical memory array of the table
+    pub unassigned_devices: Vec<MemoryDeviceTopology>,
+}
+
+impl MemoryTopology {
+    /// Builds the memory topology of a table
+    pub fn new(data: &SMBiosData) -> Self {
+        let mut arrays: Vec<MemoryArrayTopology> = data
+            .map(|array: SMBiosPhysicalMemoryArray<'_>| MemoryArrayTopology {
+                handle: array.parts().header.handle(),
+                usage: array.usage().map(|usage| format!("{:?}", usage.value)),
+                maximum_capacity_bytes: match array.maximum_capacity() {
+                    Some(MaximumMemoryCapacity::Kilobytes(kilobytes)) => {
+                        Some((kilobytes as u64) << 10)
+                    }
+                    Some(MaximumMemoryCapacity::SeeExtendedMaximumCapacity) => {
+                        array.extended_maximum_capacity()
+                    }
+                    None => None,
+                },
+                slot_count: array.number_of_memory_devices(),
+                devices: Vec::new(),
+                address_ranges: Vec::new(),
+            })
+            .collect();
+        let mut unassigned_devices = Vec::new();
+
+        for mapped_address in data.collect::<SMBiosMemoryArrayMappedAddress<'_>>() {
+            let range = AddressRange::from_fields(
+                mapped_address.starting_address(),
+                mapped_address.ending_address(),
+                mapped_address.extended_starting_address(),
+                mapped_address.extended_ending_address(),
+            );
+            let array = mapped_address
+                .physical_memory_array_handle()
+                .and_then(|handle| arrays.iter_mut().find(|array| array.handle == handle));
+            if let (Some(range), Some(array)) = (range, array) {
+                array.address_ranges.push(range);
+            }
+        }
+
+        let device_mapped_addresses = data.collect::<SMBiosMemoryDeviceMappedAddress<'_>>();
+        for device in data.collect::<SMBiosMemoryDevice<'_>>() {
+            let handle = device.parts().header.handle();
+            let device_topology = MemoryDeviceTopology {
+                handle,
+                device_locator: device.device_locator().to_utf8_lossy(),
+                bank_locator: device.bank_locator().to_utf8_lossy(),
+                populated: !matches!(device.size(), Some(MemorySize::NotInstalled)),
+                size_bytes: device.effective_size(),
+                address_ranges: device_mapped_addresses
+                    .iter()
+                    .filter(|mapped_address| mapped_address.memory_device_handle() == Some(handle))
+                    .filter_map(|mapped_address| {
+                        Some(DeviceAddressRange {
+                            handle: mapped_address.parts().header.handle(),
+                            range: AddressRange::from_fields(
+                                mapped_address.starting_address(),
+                                mapped_address.ending_address(),
+                                mapped_address.extended_starting_address(),
+                                mapped_address.extended_ending_address(),
+                            )?,
+                            memory_array_mapped_address_handle: mapped_address
+                                .memory_array_mapped_address_handle(),
+                            interleave_position: mapped_address.interleave_position(),
+                            interleaved_data_depth: mapped_address.interleaved_data_depth(),
+                        })
+                    })
+                    .collect(),
+            };
+
+            let array = device
+                .physical_memory_array_handle()
+                .and_then(|handle| arrays.iter_mut().find(|array| array.handle == handle));
+            match array {
+                Some(array) => array.devices.push(device_topology),
+                None => unassigned_devices.push(device_topology),
+            }
+        }
+
+        MemoryTopology {
+            arrays,
+            unassigned_devices,
+        }
+    }
+
+    /// All memory devices, those of each array followed by unassigned devices
+    pub fn devices(&self) -> impl Iterator<Item = &MemoryDeviceTopology> {
+        self.arrays
+            .iter()
+            .flat_map(|array| array.devices.iter())
+            .chain(self.unassigned_devices.iter())
+    }
+
+    /// Total size in bytes of all installed devices with a known size
+    pub fn installed_bytes(&self) -> u64 {
+        installed_bytes(self.devices())
+    }
+
+    /// Sum of the maximum capacities of the arrays, `None` if any is unknown
+    pub fn maximum_capacity_bytes(&self) -> Option<u64> {
+        self.arrays
+            .iter()
+            .map(|array| array.maximum_capacity_bytes)
+            .sum()
+    }
+
+    /// Number of installed memory devices
+    pub fn populated_slots(&self) -> usize {
+        self.devices().filter(|device| device.populated).count()
+    }
+
+    /// Number of sockets without a memory device
+    pub fn empty_slots(&self) -> usize {
+        self.arrays
+            .iter()
+            .map(|array| array.empty_slots())
+            .sum::<usize>()
+            + self
+                .unassigned_devices
+                .iter()
+                .filter(|device| !device.populated)
+                .count()
+    }
+
+    /// Memory devices backing the given physical byte address
+    ///
+    /// Interleaved devices share address ranges, so more than one device
+    /// may be returned.
+    pub fn devices_at_address(&self, address: u64) -> Vec<&MemoryDeviceTopology> {
+        self.devices()
+            .filter(|device| {
+                device
+                    .address_ranges
+                    .iter()
+                    .any(|device_range| device_range.range.contains(address))
+            })
+            .collect()
+    }
+}
+
+fn installed_bytes<'a>(devices: impl Iterator<Item = &'a MemoryDeviceTopology>) -> u64 {
+    devices
+        .filter(|device| device.populated)
+        .filter_map(|device| device.size_bytes)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_memory_topology() {
+        let mut path = PathBuf::new();
+        path.push(".");
+        path.push("tests");
+        path.push("jeffgerlap_3_2_0");
+        path.set_extension("dat");
+
+        let data = crate::load_smbios_data_from_file(&path).unwrap();
+        let topology = MemoryTopology::new(&data);
+
+        assert_eq!(topology.arrays.len(), 1);
+        assert!(topology.unassigned_devices.is_empty());
+        let array = &topology.arrays[0];
+        assert_eq!(array.handle, Handle(1));
+        assert_eq!(array.devices.len(), 2);
+        assert_eq!(
+            array.address_ranges,
+            vec![AddressRange {
+                start: 0,
+                end: 0x3_FFFF_FFFF
+            }]
+        );
+        assert_eq!(topology.installed_bytes(), 16 << 30);
+        assert_eq!(topology.populated_slots(), 2);
+        assert_eq!(topology.empty_slots(), 0);
+
+        // Both DIMMs are interleaved across the whole range
+        let devices: Vec<Handle> = topology
+            .devices_at_address(0x1_0000_0000)
+            .iter()
+            .map(|device| device.handle)
+            .collect();
+        assert_eq!(devices, vec![Handle(8), Handle(11)]);
+        assert!(topology.devices_at_address(0x4_0000_0000).is_empty());
+    }
+
+    #[test]
+    fn test_address_range() {
+        let range = AddressRange::from_fields(Some(0x10), Some(0x1F), None, None).unwrap();
+        assert_eq!(
+            range,
+            AddressRange {
+                start: 0x4000,
+                end: 0x7FFF
+            }
+        );
+        assert_eq!(range.byte_count(), Some(0x4000));
+
+        let extended = AddressRange::from_fields(
+            Some(SEE_EXTENDED_ADDRESS),
+            Some(SEE_EXTENDED_ADDRESS),
+            Some(0x100_0000_0000),
+            Some(0x1FF_FFFF_FFFF),
+        )
+        .unwrap();
+        assert!(extended.contains(0x180_0000_0000));
+        assert!(!extended.contains(0xFF_FFFF_FFFF));
+
+        let whole = AddressRange {
+            start: 0,
+            end: u64::MAX,
+        };
+        assert_eq!(whole.byte_count(), None);
+    }
+}
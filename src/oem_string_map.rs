@@ -0,0 +1,233 @@
+//! Key/value data encoded in OEM Strings (Type 11) structures.
+//!
+//! Vendors and hypervisors commonly store structured data in OEM strings as
+//! `key=value` or `key:value`, and systemd passes credentials as
+//! `io.systemd.credential:name=value` (or `io.systemd.credential.binary:name=value`
+//! for base64 encoded values). [OemStringMap] parses these conventions while
+//! keeping the order of the strings, duplicate keys and strings with no key.
+use crate::core::SMBiosData;
+use crate::structs::*;
+use serde::Serialize;
+
+/// Key prefixes of systemd credentials, whose key ends at the first '='
+const SYSTEMD_CREDENTIAL_PREFIXES: [&str; 2] =
+    ["io.systemd.credential:", "io.systemd.credential.binary:"];
+
+/// # OEM String Format
+///
+/// Convention an OEM string was parsed with.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum OemStringFormat {
+    /// `key=value`
+    KeyEqualsValue,
+    /// `key:value`
+    KeyColonValue,
+    /// `io.systemd.credential:name=value` or `io.systemd.credential.binary:name=value`
+    SystemdCredential,
+    /// A string with no key
+    Unkeyed,
+}
+
+/// # OEM String Entry
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct OemStringEntry {
+    /// Key of the entry, `None` for a string with no key
+    ///
+    /// For systemd credentials the key includes the prefix,
+    /// e.g. "io.systemd.credential:foo".
+    pub key: Option<String>,
+    /// Value of the entry, the whole string for a string with no key
+    pub value: String,
+    /// Convention the string was parsed with
+    pub format: OemStringFormat,
+    /// `false` if the string is not valid UTF-8 and invalid
+    /// characters were replaced with U+FFFD
+    pub valid_utf8: bool,
+}
+
+impl OemStringEntry {
+    /// Parses an OEM string into a key and value
+    pub fn parse(raw: &[u8]) -> Self {
+        let (string, valid_utf8) = match std::str::from_utf8(raw) {
+            Ok(string) => (string.to_string(), true),
+            Err(_) => (String::from_utf8_lossy(raw).to_string(), false),
+        };
+
+        let (key, value, format) = if SYSTEMD_CREDENTIAL_PREFIXES
+            .iter()
+            .any(|prefix| string.starts_with(prefix))
+        {
+            match string.split_once('=') {
+                // Credential values are kept exactly as given
+                Some((key, value)) => (
+                    Some(key.to_string()),
+                    value.to_string(),
+                    OemStringFormat::SystemdCredential,
+                ),
+                None => (None, string.clone(), OemStringFormat::Unkeyed),
+            }
+        } else {
+            match string.find(['=', ':']) {
+                Some(index) if !string[..index].trim().is_empty() => {
+                    let format = match &string[index..index + 1] {
+                        "=" => OemStringFormat::KeyEqualsValue,
+                        _ => OemStringFormat::KeyColonValue,
+                    };
+                    (
+                        Some(string[..index].trim().to_string()),
+                        string[index + 1..].trim().to_string(),
+                        format,
+                    )
+                }
+                _ => (None, string.clone(), OemStringFormat::Unkeyed),
+            }
+        };
+
+        OemStringEntry {
+            key,
+            value,
+            format,
+            valid_utf8,
+        }
+    }
+}
+
+/// # OEM String Map
+///
+/// Ordered entries parsed from the strings of one or more OEM Strings structures.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct OemStringMap {
+    /// Entries in the order of the strings
+    pub entries: Vec<OemStringEntry>,
+}
+
+impl OemStringMap {
+    /// Parses the strings of an OEM Strings structure
+    pub fn new(oem_strings: &SMBiosOemStrings<'_>) -> Self {
+        let mut map = OemStringMap::default();
+        map.extend(oem_strings);
+        map
+    }
+
+    /// Parses the strings of every OEM Strings structure of a table
+    pub fn from_table(data: &SMBiosData) -> Self {
+        let mut map = OemStringMap::default();
+        for oem_strings in data.collect::<SMBiosOemStrings<'_>>() {
+            map.extend(&oem_strings);
+        }
+        map
+    }
+
+    fn extend(&mut self, oem_strings: &SMBiosOemStrings<'_>) {
+        self.entries.extend(
+            oem_strings
+                .oem_strings()
+                .iter()
+                .map(|raw| OemStringEntry::parse(raw)),
+        );
+    }
+
+    /// Value of the first entry with the given key
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|entry| entry.key.as_deref() == Some(key))
+            .map(|entry| entry.value.as_str())
+    }
+
+    /// Values of all entries with the given key, in order
+    pub fn get_all<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.key.as_deref() == Some(key))
+            .map(|entry| entry.value.as_str())
+    }
+
+    /// Keyed entries as key/value pairs, in order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries
+            .iter()
+            .filter_map(|entry| Some((entry.key.as_deref()?, entry.value.as_str())))
+    }
+
+    /// Strings with no key, in order
+    pub fn unkeyed(&self) -> impl Iterator<Item = &str> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.key.is_none())
+            .map(|entry| entry.value.as_str())
+    }
+}
+
+impl SMBiosData {
+    /// Parses the OEM strings of the table into key/value entries
+    pub fn oem_string_map(&self) -> OemStringMap {
+        OemStringMap::from_table(self)
+    }
+
+    /// Value of the first OEM string with the given key
+    ///
+    /// e.g. `oem_value("io.systemd.credential:foo")`
+    pub fn oem_value(&self, key: &str) -> Option<String> {
+        self.oem_string_map().get(key).map(str::to_string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oem_string_map() {
+        let mut table = vec![0x0B, 0x05, 0x10, 0x00, 0x07];
+        for string in [
+            &b"io.systemd.credential:foo=bar=baz "[..],
+            b"io.systemd.credential.binary:key=aGVsbG8=",
+            b"vendor = Acme",
+            b"Asset Tag: 1234",
+            b"Asset Tag: 5678",
+            b"Dell System",
+            b"serial=\xFF01",
+        ]
+        .iter()
+        {
+            table.extend_from_slice(string);
+            table.push(0x00);
+        }
+        table.push(0x00);
+        let data = SMBiosData::from_vec_and_version(table, None);
+        let map = data.oem_string_map();
+
+        assert_eq!(map.entries.len(), 7);
+        assert_eq!(
+            map.entries[0],
+            OemStringEntry {
+                key: Some("io.systemd.credential:foo".to_string()),
+                value: "bar=baz ".to_string(),
+                format: OemStringFormat::SystemdCredential,
+                valid_utf8: true,
+            }
+        );
+        assert_eq!(
+            map.get("io.systemd.credential.binary:key"),
+            Some("aGVsbG8=")
+        );
+        assert_eq!(map.entries[2].format, OemStringFormat::KeyEqualsValue);
+        assert_eq!(map.get("vendor"), Some("Acme"));
+        assert_eq!(map.entries[3].format, OemStringFormat::KeyColonValue);
+        assert_eq!(
+            map.get_all("Asset Tag").collect::<Vec<_>>(),
+            vec!["1234", "5678"]
+        );
+        assert_eq!(map.unkeyed().collect::<Vec<_>>(), vec!["Dell System"]);
+        assert!(!map.entries[6].valid_utf8);
+        assert_eq!(map.get("serial"), Some("\u{FFFD}01"));
+        assert_eq!(map.iter().count(), 6);
+
+        assert_eq!(
+            data.oem_value("io.systemd.credential:foo"),
+            Some("bar=baz ".to_string())
+        );
+        assert_eq!(data.oem_value("missing"), None);
+    }
+}
@@ -0,0 +1,292 @@
+//! Processor topology joined from Types 4, 7 and 44.
+//!
+//! [ProcessorTopology] describes each Processor Information (Type 4) socket
+//! together with the Cache Information (Type 7) structures named by its
+//! cache handles and the Processor Additional Information (Type 44)
+//! structures referring to it. Counts are resolved from the "2" fields
+//! where the original fields cannot hold them.
+use crate::core::{Handle, SMBiosData};
+use crate::structs::*;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+
+/// # Cache Topology
+#[derive(Serialize, Debug, Clone)]
+pub struct CacheTopology {
+    /// Handle of the Cache Information structure
+    pub handle: Handle,
+    /// Cache level, 1 for L1
+    pub level: u8,
+    /// Socket designation, e.g. "L1 - Cache"
+    pub socket_designation: Option<String>,
+    /// Installed size in bytes, if known
+    pub installed_size_bytes: Option<u64>,
+    /// Maximum size in bytes, if known
+    pub maximum_size_bytes: Option<u64>,
+    /// Logical type of the cache, e.g. "Unified"
+    pub system_cache_type: Option<String>,
+}
+
+/// # Processor Additional Information Topology
+#[derive(Serialize, Debug, Clone)]
+pub struct ProcessorAdditionalTopology {
+    /// Handle of the Processor Additional Information structure
+    pub handle: Handle,
+    /// Architecture of the processor specific block, e.g. "X64"
+    pub architecture: Option<String>,
+    /// Processor specific data of the block
+    pub processor_specific_data: Vec<u8>,
+}
+
+/// # Processor Socket Topology
+#[derive(Serialize, Debug)]
+pub struct ProcessorSocketTopology {
+    /// Handle of the Processor Information structure
+    pub handle: Handle,
+    /// Socket designation, e.g. "CPU0"
+    pub socket_designation: Option<String>,
+    /// `true` if a processor is installed in the socket
+    pub populated: bool,
+    /// Processor family resolved from 'processor_family' and 'processor_family_2'
+    pub family: Option<String>,
+    /// Processor manufacturer
+    pub manufacturer: Option<String>,
+    /// Processor version, e.g. "Intel(R) Xeon(R) W-2133 CPU @ 3.60GHz"
+    pub version: Option<String>,
+    /// Number of cores in the socket, if known
+    pub core_count: Option<u16>,
+    /// Number of enabled cores in the socket, if known
+    pub cores_enabled: Option<u16>,
+    /// Number of threads in the socket, if known
+    pub thread_count: Option<u16>,
+    /// Caches named by the l1, l2 and l3 cache handles
+    pub caches: Vec<CacheTopology>,
+    /// Processor Additional Information structures referring to the socket
+    pub additional_information: Vec<ProcessorAdditionalTopology>,
+}
+
+impl ProcessorSocketTopology {
+    /// Total installed size in bytes of the socket's caches of the given level
+    pub fn cache_bytes(&self, level: u8) -> u64 {
+        self.caches
+            .iter()
+            .filter(|cache| cache.level == level)
+            .filter_map(|cache| cache.installed_size_bytes)
+            .sum()
+    }
+}
+
+/// # Processor Topology
+#[derive(Serialize, Debug)]
+pub struct ProcessorTopology {
+    /// Processor sockets with their caches and additional information
+    pub sockets: Vec<ProcessorSocketTopology>,
+}
+
+impl ProcessorTopology {
+    /// Builds the processor topology of a table
+    pub fn new(data: &SMBiosData) -> Self {
+        let additional_information = data.collect::<SMBiosProcessorAdditionalInformation<'_>>();
+
+        let sockets = data
+            .map(|processor: SMBiosProcessorInformation<'_>| {
+                let handle = processor.parts().header.handle();
+                let cache_handles = [
+                    (1, processor.l1cache_handle()),
+                    (2, processor.l2cache_handle()),
+                    (3, processor.l3cache_handle()),
+                ];
+
+                ProcessorSocketTopology {
+                    handle,
+                    socket_designation: processor.socket_designation().to_utf8_lossy(),
+                    populated: matches!(
+                        processor.status(),
+                        Some(status) if status.socket_populated()
+                    ),
+                    family: processor
+                        .effective_processor_family()
+                        .map(|family| format!("{}", family)),
+                    manufacturer: processor.processor_manufacturer().to_utf8_lossy(),
+                    version: processor.processor_version().to_utf8_lossy(),
+                    core_count: processor.effective_core_count(),
+                    cores_enabled: processor.effective_cores_enabled(),
+                    thread_count: processor.effective_thread_count(),
+                    caches: cache_handles
+                        .iter()
+                        .filter_map(|&(level, cache_handle)| {
+                            cache_topology(data, level, cache_handle?)
+                        })
+                        .collect(),
+                    additional_information: additional_information
+                        .iter()
+                        .filter(|additional| additional.referenced_handle() == Some(handle))
+                        .map(|additional| {
+                            let block = additional.processor_specific_block();
+                            ProcessorAdditionalTopology {
+                                handle: additional.parts().header.handle(),
+                                architecture: block
+                                    .as_ref()
+                                    .map(|block| format!("{}", block.processor_type())),
+                                processor_specific_data: block
+                                    .map(|block| block.processor_specific_data().to_vec())
+                                    .unwrap_or_default(),
+                            }
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        ProcessorTopology { sockets }
+    }
+
+    /// Populated processor sockets
+    pub fn populated_sockets(&self) -> impl Iterator<Item = &ProcessorSocketTopology> {
+        self.sockets.iter().filter(|socket| socket.populated)
+    }
+
+    /// Number of sockets with a processor installed
+    pub fn populated_socket_count(&self) -> usize {
+        self.populated_sockets().count()
+    }
+
+    /// Total cores of the populated sockets with a known core count
+    pub fn core_count(&self) -> u32 {
+        self.populated_sockets()
+            .filter_map(|socket| socket.core_count)
+            .map(u32::from)
+            .sum()
+    }
+
+    /// Total threads of the populated sockets with a known thread count
+    pub fn thread_count(&self) -> u32 {
+        self.populated_sockets()
+            .filter_map(|socket| socket.thread_count)
+            .map(u32::from)
+            .sum()
+    }
+
+    /// Total installed cache size in bytes of the populated sockets, by cache level
+    ///
+    /// A cache shared between sockets is counted once.
+    pub fn cache_bytes_per_level(&self) -> BTreeMap<u8, u64> {
+        let mut seen = HashSet::new();
+        let mut per_level = BTreeMap::new();
+        for cache in self
+            .populated_sockets()
+            .flat_map(|socket| socket.caches.iter())
+        {
+            if !seen.insert(cache.handle) {
+                continue;
+            }
+            *per_level.entry(cache.level).or_insert(0) += cache.installed_size_bytes.unwrap_or(0);
+        }
+        per_level
+    }
+}
+
+/// Describes the cache structure a processor names in its cache handle of the given level
+///
+/// The level is taken from the cache's own configuration where present.
+fn cache_topology(data: &SMBiosData, level: u8, handle: Handle) -> Option<CacheTopology> {
+    let cache = match data.find_by_handle(&handle)?.defined_struct() {
+        DefinedStruct::CacheInformation(cache) => cache,
+        _ => return None,
+    };
+
+    Some(CacheTopology {
+        handle,
+        level: cache
+            .cache_configuration()
+            .map_or(level, |configuration| configuration.cache_level()),
+        socket_designation: cache.socket_designation().to_utf8_lossy(),
+        installed_size_bytes: cache.effective_installed_cache_size(),
+        maximum_size_bytes: cache.effective_maximum_cache_size(),
+        system_cache_type: cache
+            .system_cache_type()
+            .map(|cache_type| format!("{}", cache_type)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_processor_topology() {
+        let mut path = PathBuf::new();
+        path.push(".");
+        path.push("tests");
+        path.push("jeffgerlap_3_2_0");
+        path.set_extension("dat");
+
+        let data = crate::load_smbios_data_from_file(&path).unwrap();
+        let topology = ProcessorTopology::new(&data);
+
+        assert_eq!(topology.sockets.len(), 1);
+        let socket = &topology.sockets[0];
+        assert_eq!(socket.handle, Handle(6));
+        assert_eq!(socket.socket_designation.as_deref(), Some("FP5"));
+        assert!(socket.populated);
+        assert_eq!(socket.core_count, Some(4));
+        assert_eq!(socket.thread_count, Some(8));
+
+        let cache_levels: Vec<(Handle, u8)> = socket
+            .caches
+            .iter()
+            .map(|cache| (cache.handle, cache.level))
+            .collect();
+        assert_eq!(
+            cache_levels,
+            vec![(Handle(3), 1), (Handle(4), 2), (Handle(5), 3)]
+        );
+
+        assert_eq!(topology.populated_socket_count(), 1);
+        assert_eq!(topology.core_count(), 4);
+        assert_eq!(topology.thread_count(), 8);
+        let per_level = topology.cache_bytes_per_level();
+        assert_eq!(per_level.len(), 3);
+        assert_eq!(per_level[&2], socket.cache_bytes(2));
+    }
+
+    #[test]
+    fn test_shared_cache_counted_once() {
+        let processor = |handle: u8| {
+            let mut struct_type4 = vec![0u8; 0x30];
+            struct_type4[0] = 0x04;
+            struct_type4[1] = 0x30;
+            struct_type4[2] = handle;
+            struct_type4[0x18] = 0x41; // populated, enabled
+            struct_type4[0x1A..0x1C].copy_from_slice(&0xFFFFu16.to_le_bytes());
+            struct_type4[0x1C..0x1E].copy_from_slice(&0xFFFFu16.to_le_bytes());
+            struct_type4[0x1E..0x20].copy_from_slice(&0x0010u16.to_le_bytes());
+            struct_type4[0x23] = 8;
+            struct_type4[0x25] = 16;
+            struct_type4.extend_from_slice(&[0x00, 0x00]);
+            struct_type4
+        };
+        // 32 MB L3 cache at handle 0x10
+        let struct_type7 = vec![
+            0x07, 0x13, 0x10, 0x00, 0x00, 0x02, 0x01, 0x00, 0x82, 0x00, 0x82, 0x20, 0x00, 0x20,
+            0x00, 0x00, 0x05, 0x05, 0x08, 0x00, 0x00,
+        ];
+
+        let mut table = processor(0x01);
+        table.extend(processor(0x02));
+        table.extend(struct_type7);
+        let data = SMBiosData::from_vec_and_version(table, None);
+        let topology = ProcessorTopology::new(&data);
+
+        assert_eq!(topology.populated_socket_count(), 2);
+        assert_eq!(topology.core_count(), 16);
+        assert_eq!(topology.thread_count(), 32);
+        assert_eq!(topology.sockets[0].cache_bytes(3), 32 * 1024 * 1024);
+        assert_eq!(topology.sockets[1].cache_bytes(3), 32 * 1024 * 1024);
+        assert_eq!(
+            topology.cache_bytes_per_level(),
+            vec![(3, 32 * 1024 * 1024)].into_iter().collect()
+        );
+    }
+}
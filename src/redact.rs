@@ -0,0 +1,369 @@
+//! Redacts identifying information from SMBIOS tables.
+//!
+//! SMBIOS tables contain serial numbers, asset tags and the system UUID.
+//! Before sharing a dump these can be rewritten in place, either zeroed or
+//! replaced with a deterministic hash of the original value. Redacted strings
+//! keep their byte length and every structure keeps its layout, so the
+//! resulting table remains valid and can be written with [crate::dump_raw]
+//! via [SMBiosData::to_bytes].
+//!
+//! Note: structures may share one string-set entry between several fields.
+//! Redacting such a field rewrites the shared entry for all of them.
+use crate::core::{SMBiosData, SMBiosStringSet, UndefinedStruct};
+use crate::structs::*;
+use serde::Serialize;
+
+/// # Redacted Field
+///
+/// An identifying field which can be redacted.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RedactedField {
+    /// System Information (Type 1) Serial Number
+    SystemSerialNumber,
+    /// System Information (Type 1) UUID
+    SystemUuid,
+    /// Baseboard Information (Type 2) Serial Number
+    BaseboardSerialNumber,
+    /// Baseboard Information (Type 2) Asset Tag
+    BaseboardAssetTag,
+    /// System Enclosure or Chassis (Type 3) Serial Number
+    ChassisSerialNumber,
+    /// System Enclosure or Chassis (Type 3) Asset Tag Number
+    ChassisAssetTag,
+    /// Processor Information (Type 4) Serial Number
+    ProcessorSerialNumber,
+    /// Processor Information (Type 4) Asset Tag
+    ProcessorAssetTag,
+    /// Memory Device (Type 17) Serial Number
+    MemoryDeviceSerialNumber,
+    /// Memory Device (Type 17) Asset Tag
+    MemoryDeviceAssetTag,
+    /// Portable Battery (Type 22) Serial Number
+    PortableBatterySerialNumber,
+    /// System Power Supply (Type 39) Serial Number
+    PowerSupplySerialNumber,
+    /// System Power Supply (Type 39) Asset Tag Number
+    PowerSupplyAssetTag,
+}
+
+impl RedactedField {
+    /// All redactable fields
+    pub const ALL: [RedactedField; 13] = [
+        RedactedField::SystemSerialNumber,
+        RedactedField::SystemUuid,
+        RedactedField::BaseboardSerialNumber,
+        RedactedField::BaseboardAssetTag,
+        RedactedField::ChassisSerialNumber,
+        RedactedField::ChassisAssetTag,
+        RedactedField::ProcessorSerialNumber,
+        RedactedField::ProcessorAssetTag,
+        RedactedField::MemoryDeviceSerialNumber,
+        RedactedField::MemoryDeviceAssetTag,
+        RedactedField::PortableBatterySerialNumber,
+        RedactedField::PowerSupplySerialNumber,
+        RedactedField::PowerSupplyAssetTag,
+    ];
+
+    /// The structure type and field offset of this field
+    pub fn location(&self) -> (u8, usize) {
+        match self {
+            RedactedField::SystemSerialNumber => (SMBiosSystemInformation::STRUCT_TYPE, 0x07),
+            RedactedField::SystemUuid => (SMBiosSystemInformation::STRUCT_TYPE, 0x08),
+            RedactedField::BaseboardSerialNumber => (SMBiosBaseboardInformation::STRUCT_TYPE, 0x07),
+            RedactedField::BaseboardAssetTag => (SMBiosBaseboardInformation::STRUCT_TYPE, 0x08),
+            RedactedField::ChassisSerialNumber => {
+                (SMBiosSystemChassisInformation::STRUCT_TYPE, 0x07)
+            }
+            RedactedField::ChassisAssetTag => (SMBiosSystemChassisInformation::STRUCT_TYPE, 0x08),
+            RedactedField::ProcessorSerialNumber => (SMBiosProcessorInformation::STRUCT_TYPE, 0x20),
+            RedactedField::ProcessorAssetTag => (SMBiosProcessorInformation::STRUCT_TYPE, 0x21),
+            RedactedField::MemoryDeviceSerialNumber => (SMBiosMemoryDevice::STRUCT_TYPE, 0x18),
+            RedactedField::MemoryDeviceAssetTag => (SMBiosMemoryDevice::STRUCT_TYPE, 0x19),
+            RedactedField::PortableBatterySerialNumber => {
+                (SMBiosPortableBattery::STRUCT_TYPE, 0x07)
+            }
+            RedactedField::PowerSupplySerialNumber => (SMBiosSystemPowerSupply::STRUCT_TYPE, 0x08),
+            RedactedField::PowerSupplyAssetTag => (SMBiosSystemPowerSupply::STRUCT_TYPE, 0x09),
+        }
+    }
+}
+
+/// # Redaction Method
+///
+/// How the value of a [RedactedField] is rewritten.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RedactionMethod {
+    /// Leave the value as is
+    Keep,
+    /// Replace every character of a string with '0' and every byte of the UUID with 00h
+    Zero,
+    /// Replace the value with a deterministic hash of the original value
+    ///
+    /// Strings are replaced with upper case hexadecimal digits of the same
+    /// byte length. The same input always produces the same output, which
+    /// allows correlating dumps without revealing the original value.
+    Hash,
+}
+
+/// # Redaction Policy
+///
+/// The [RedactionMethod] applied to each [RedactedField].
+///
+/// The default policy hashes every field.
+///
+/// ```
+/// use smbioslib::*;
+///
+/// let policy = RedactionPolicy::default()
+///     .with_method(RedactedField::SystemUuid, RedactionMethod::Zero)
+///     .with_method(RedactedField::ProcessorSerialNumber, RedactionMethod::Keep);
+/// assert_eq!(policy.method(RedactedField::SystemUuid), RedactionMethod::Zero);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RedactionPolicy {
+    methods: Vec<(RedactedField, RedactionMethod)>,
+    salt: Vec<u8>,
+}
+
+impl RedactionPolicy {
+    /// Creates a policy applying `method` to every field
+    pub fn new(method: RedactionMethod) -> Self {
+        RedactionPolicy {
+            methods: RedactedField::ALL
+                .iter()
+                .map(|&field| (field, method))
+                .collect(),
+            salt: Vec::new(),
+        }
+    }
+
+    /// Sets the method used for `field`
+    pub fn with_method(mut self, field: RedactedField, method: RedactionMethod) -> Self {
+        for entry in self.methods.iter_mut() {
+            if entry.0 == field {
+                entry.1 = method;
+            }
+        }
+        self
+    }
+
+    /// Sets a salt mixed into every [RedactionMethod::Hash] result
+    ///
+    /// Short values such as serial numbers can be recovered from an unsalted
+    /// hash by brute force. A salt kept private by the sender prevents this
+    /// while still producing consistent results across dumps.
+    pub fn with_salt(mut self, salt: &[u8]) -> Self {
+        self.salt = salt.to_vec();
+        self
+    }
+
+    /// The method used for `field`
+    pub fn method(&self, field: RedactedField) -> RedactionMethod {
+        self.methods
+            .iter()
+            .find(|(entry_field, _)| *entry_field == field)
+            .map(|(_, method)| *method)
+            .unwrap_or(RedactionMethod::Keep)
+    }
+}
+
+impl Default for RedactionPolicy {
+    fn default() -> Self {
+        RedactionPolicy::new(RedactionMethod::Hash)
+    }
+}
+
+impl SMBiosData {
+    /// Redacts identifying fields of the contained structures according to `policy`
+    pub fn redact(&mut self, policy: &RedactionPolicy) {
+        for undefined_struct in self.iter_mut() {
+            redact_struct(undefined_struct, policy);
+        }
+    }
+}
+
+/// Redacts identifying fields of a single structure according to `policy`
+pub fn redact_struct(undefined_struct: &mut UndefinedStruct, policy: &RedactionPolicy) {
+    let struct_type = undefined_struct.header.struct_type();
+    for field in RedactedField::ALL.iter() {
+        let (field_struct_type, offset) = field.location();
+        if field_struct_type != struct_type {
+            continue;
+        }
+
+        match (field, policy.method(*field)) {
+            (_, RedactionMethod::Keep) => (),
+            (RedactedField::SystemUuid, method) => {
+                if let Some(uuid) = undefined_struct.fields.get_mut(offset..offset + 0x10) {
+                    redact_uuid(uuid, method, &policy.salt);
+                }
+            }
+            (_, method) => {
+                if let Some(string_number) = undefined_struct.get_field_byte(offset) {
+                    redact_string(
+                        &mut undefined_struct.strings,
+                        string_number,
+                        method,
+                        &policy.salt,
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn redact_string(
+    strings: &mut SMBiosStringSet,
+    string_number: u8,
+    method: RedactionMethod,
+    salt: &[u8],
+) {
+    let index = string_number as usize;
+    if index == 0 || index > strings.iter().len() {
+        return;
+    }
+
+    let mut string_area: Vec<u8> = Vec::new();
+    for (current_index, string) in strings.iter().enumerate() {
+        if current_index + 1 == index {
+            string_area.extend(redacted_bytes(string, method, salt));
+        } else {
+            string_area.extend_from_slice(string);
+        }
+        string_area.push(0);
+    }
+    // SMBiosStringSet::new expects the string area without the final terminator
+    string_area.pop();
+    *strings = SMBiosStringSet::new(string_area);
+}
+
+/// Produces a replacement of the same length which contains no NUL bytes.
+fn redacted_bytes(original: &[u8], method: RedactionMethod, salt: &[u8]) -> Vec<u8> {
+    match method {
+        RedactionMethod::Keep => original.to_vec(),
+        RedactionMethod::Zero => vec![b'0'; original.len()],
+        RedactionMethod::Hash => {
+            const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+            hash_stream(original, salt)
+                .flat_map(|byte| {
+                    vec![
+                        HEX_DIGITS[(byte >> 4) as usize],
+                        HEX_DIGITS[(byte & 0xF) as usize],
+                    ]
+                })
+                .take(original.len())
+                .collect()
+        }
+    }
+}
+
+fn redact_uuid(uuid: &mut [u8], method: RedactionMethod, salt: &[u8]) {
+    match method {
+        RedactionMethod::Keep => (),
+        RedactionMethod::Zero => uuid.iter_mut().for_each(|byte| *byte = 0),
+        RedactionMethod::Hash => {
+            // All 00h and all FFh are reserved to mean the UUID is not present
+            let present =
+                uuid.iter().any(|&byte| byte != 0) && uuid.iter().any(|&byte| byte != 0xFF);
+            if present {
+                let hashed: Vec<u8> = hash_stream(uuid, salt).take(uuid.len()).collect();
+                uuid.copy_from_slice(&hashed);
+            }
+        }
+    }
+}
+
+/// An endless stream of bytes derived from `data` and `salt` using FNV-1a.
+///
+/// FNV-1a is not cryptographic but it is stable across platforms and
+/// compiler versions, which std's `DefaultHasher` does not guarantee.
+fn hash_stream<'a>(data: &'a [u8], salt: &'a [u8]) -> impl Iterator<Item = u8> + 'a {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    (0u64..).flat_map(move |block| {
+        let hash = block
+            .to_le_bytes()
+            .iter()
+            .chain(salt.iter())
+            .chain(data.iter())
+            .fold(FNV_OFFSET_BASIS, |hash, &byte| {
+                (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+            });
+        hash.to_le_bytes().to_vec()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SMBiosVersion;
+
+    fn type1_table() -> SMBiosData {
+        let struct_type1 = vec![
+            0x01, 0x1B, 0x01, 0x00, 0x01, 0x02, 0x03, 0x04, 0xD2, 0x01, 0x25, 0x3E, 0x48, 0xE6,
+            0x11, 0xE8, 0xBA, 0xD3, 0x70, 0x20, 0x84, 0x0F, 0x9D, 0x47, 0x06, 0x05, 0x06, b'L',
+            b'E', b'N', b'O', b'V', b'O', 0x00, b'3', b'0', b'B', b'F', b'S', b'0', b'7', b'5',
+            b'0', b'0', 0x00, b'T', b'h', b'i', b'n', b'k', b'S', b't', b'a', b't', b'i', b'o',
+            b'n', b' ', b'P', b'5', b'2', b'0', 0x00, b'M', b'N', b'0', b'6', b'P', b'Q', b'R',
+            b'S', 0x00, b'S', b'K', b'U', 0x00, b'F', b'a', b'm', b'i', b'l', b'y', 0x00, 0x00,
+        ];
+        SMBiosData::from_vec_and_version(struct_type1, Some(SMBiosVersion::new(3, 2, 0)))
+    }
+
+    #[test]
+    fn test_redact_zero() {
+        let mut data = type1_table();
+        let original_len = data.to_bytes().len();
+        data.redact(&RedactionPolicy::new(RedactionMethod::Zero));
+
+        assert_eq!(data.to_bytes().len(), original_len);
+        let system = data.first::<SMBiosSystemInformation<'_>>().unwrap();
+        assert_eq!(system.serial_number().to_string(), "00000000");
+        assert_eq!(system.manufacturer().to_string(), "LENOVO");
+        assert_eq!(system.family().to_string(), "Family");
+        assert!(matches!(
+            system.uuid(),
+            Some(SystemUuidData::IdNotPresentButSettable)
+        ));
+    }
+
+    #[test]
+    fn test_redact_hash_is_deterministic() {
+        let mut first = type1_table();
+        let mut second = type1_table();
+        let policy = RedactionPolicy::default();
+        first.redact(&policy);
+        second.redact(&policy);
+        assert_eq!(first.to_bytes(), second.to_bytes());
+
+        let system = first.first::<SMBiosSystemInformation<'_>>().unwrap();
+        let serial = system.serial_number().to_string();
+        assert_eq!(serial.len(), "MN06PQRS".len());
+        assert_ne!(serial, "MN06PQRS");
+        assert!(serial.bytes().all(|byte| byte.is_ascii_hexdigit()));
+        assert_ne!(
+            format!("{}", system.uuid().unwrap()),
+            "3e2501d2-e648-e811-bad3-7020840f9d47"
+        );
+
+        let mut salted = type1_table();
+        salted.redact(&RedactionPolicy::default().with_salt(b"secret"));
+        assert_ne!(first.to_bytes(), salted.to_bytes());
+    }
+
+    #[test]
+    fn test_redact_keep() {
+        let mut data = type1_table();
+        let policy = RedactionPolicy::new(RedactionMethod::Zero)
+            .with_method(RedactedField::SystemUuid, RedactionMethod::Keep);
+        data.redact(&policy);
+
+        let system = data.first::<SMBiosSystemInformation<'_>>().unwrap();
+        assert_eq!(system.serial_number().to_string(), "00000000");
+        assert_eq!(
+            format!("{}", system.uuid().unwrap()),
+            "3e2501d2-e648-e811-bad3-7020840f9d47"
+        );
+    }
+}
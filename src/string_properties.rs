@@ -0,0 +1,71 @@
+//! String Property (Type 46) structures resolved onto their parent structures.
+//!
+//! A String Property names its parent by handle, so reading the properties of
+//! a structure requires the table. [SMBiosData::string_properties_for] collects
+//! them and [SMBiosData::uefi_device_path_for] reads the UEFI device path.
+//! System Slots (Type 9) and Onboard Devices Extended Information (Type 41),
+//! the parents which commonly carry one, read it with `uefi_device_path(&data)`.
+use crate::core::{Handle, SMBiosData};
+use crate::structs::*;
+
+impl SMBiosData {
+    /// String Property structures whose parent is the structure with the given handle
+    pub fn string_properties_for(&self, handle: Handle) -> Vec<SMBiosStringProperty<'_>> {
+        self.filter(move |property: &SMBiosStringProperty<'_>| {
+            property.parent_handle() == Some(handle)
+        })
+        .collect()
+    }
+
+    /// UEFI device path of the structure with the given handle
+    pub fn uefi_device_path_for(&self, handle: Handle) -> Option<UefiDevicePath> {
+        self.string_properties_for(handle)
+            .iter()
+            .find_map(|property| property.uefi_device_path())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string_properties() {
+        // System Slot at handle 9 and Onboard Device at handle 0x41
+        let mut table = vec![
+            0x09, 0x11, 0x09, 0x00, 0x01, 0xA5, 0x0D, 0x04, 0x04, 0x00, 0x00, 0x0C, 0x01, 0x00,
+            0x00, 0x01, 0x00,
+        ];
+        table.extend_from_slice(b"SLOT1\0\0");
+        table.extend_from_slice(&[
+            0x29, 0x0B, 0x41, 0x00, 0x01, 0x85, 0x01, 0x00, 0x00, 0x02, 0x00,
+        ]);
+        table.extend_from_slice(b"Onboard LAN\0\0");
+        // UEFI device path and vendor specific properties of the slot,
+        // UEFI device path of the onboard device
+        table.extend_from_slice(&[0x2E, 0x09, 0x60, 0x00, 0x01, 0x00, 0x01, 0x09, 0x00]);
+        table.extend_from_slice(b"PciRoot(0x0)/Pci(0x1C,0x0)\0\0");
+        table.extend_from_slice(&[0x2E, 0x09, 0x61, 0x00, 0x00, 0x80, 0x01, 0x09, 0x00]);
+        table.extend_from_slice(b"vendor\0\0");
+        table.extend_from_slice(&[0x2E, 0x09, 0x62, 0x00, 0x01, 0x00, 0x01, 0x41, 0x00]);
+        table.extend_from_slice(b"PciRoot(0x0)/Pci(0x1F,0x6)\0\0");
+        let data = SMBiosData::from_vec_and_version(table, None);
+
+        assert_eq!(data.string_properties_for(Handle(9)).len(), 2);
+        assert!(data.string_properties_for(Handle(0x60)).is_empty());
+
+        let slot = data.first::<SMBiosSystemSlot<'_>>().unwrap();
+        let path = slot.uefi_device_path(&data).unwrap();
+        assert_eq!(path.nodes.len(), 2);
+        assert_eq!(path.nodes[1].arguments, vec!["0x1C", "0x0"]);
+
+        let device = data
+            .first::<SMBiosOnboardDevicesExtendedInformation<'_>>()
+            .unwrap();
+        assert_eq!(
+            device.uefi_device_path(&data).unwrap().to_string(),
+            "PciRoot(0x0)/Pci(0x1F,0x6)"
+        );
+        assert_eq!(data.uefi_device_path_for(Handle(0x62)), None);
+    }
+}
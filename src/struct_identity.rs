@@ -0,0 +1,62 @@
+//! Identifies structures independently of their handles.
+//!
+//! Handles may be renumbered between boots or firmware versions, so the
+//! table diff pairs structures by this identity and the DOT export uses it
+//! to label nodes.
+use crate::core::UndefinedStruct;
+use crate::structs::DefinedStruct;
+
+/// Returns the string which identifies a structure independently of its handle.
+///
+/// Types which describe a physical location (sockets, slots, connectors,
+/// memory devices, probes, etc.) are identified by their locator or
+/// designation strings. Other types return `None`.
+pub fn struct_identity(undefined_struct: &UndefinedStruct) -> Option<String> {
+    let identity = match undefined_struct.defined_struct() {
+        DefinedStruct::ProcessorInformation(s) => s.socket_designation().to_utf8_lossy(),
+        DefinedStruct::CacheInformation(s) => s.socket_designation().to_utf8_lossy(),
+        DefinedStruct::MemoryModuleInformation(s) => s.socket_designation().to_utf8_lossy(),
+        DefinedStruct::PortConnectorInformation(s) => join_identity(&[
+            s.internal_reference_designator().to_utf8_lossy(),
+            s.external_reference_designator().to_utf8_lossy(),
+        ]),
+        DefinedStruct::SystemSlot(s) => s.slot_designation().to_utf8_lossy(),
+        DefinedStruct::MemoryDevice(s) => join_identity(&[
+            s.device_locator().to_utf8_lossy(),
+            s.bank_locator().to_utf8_lossy(),
+        ]),
+        DefinedStruct::PortableBattery(s) => join_identity(&[
+            s.location().to_utf8_lossy(),
+            s.device_name().to_utf8_lossy(),
+        ]),
+        DefinedStruct::VoltageProbe(s) => s.description().to_utf8_lossy(),
+        DefinedStruct::CoolingDevice(s) => s.description().to_utf8_lossy(),
+        DefinedStruct::TemperatureProbe(s) => s.description().to_utf8_lossy(),
+        DefinedStruct::ElectricalCurrentProbe(s) => s.description().to_utf8_lossy(),
+        DefinedStruct::ManagementDevice(s) => s.description().to_utf8_lossy(),
+        DefinedStruct::ManagementDeviceComponent(s) => s.description().to_utf8_lossy(),
+        DefinedStruct::SystemPowerSupply(s) => join_identity(&[
+            s.location().to_utf8_lossy(),
+            s.device_name().to_utf8_lossy(),
+        ]),
+        DefinedStruct::OnboardDevicesExtendedInformation(s) => {
+            s.reference_designation().to_utf8_lossy()
+        }
+        _ => None,
+    };
+
+    identity.filter(|identity| !identity.trim().is_empty())
+}
+
+fn join_identity(parts: &[Option<String>]) -> Option<String> {
+    let parts: Vec<&str> = parts
+        .iter()
+        .filter_map(|part| part.as_deref())
+        .filter(|part| !part.trim().is_empty())
+        .collect();
+
+    match parts.is_empty() {
+        true => None,
+        false => Some(parts.join("/")),
+    }
+}
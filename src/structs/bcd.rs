@@ -0,0 +1,83 @@
+use serde::Serialize;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// Decodes a two digit packed BCD byte
+///
+/// Returns `None` if either nibble is not a decimal digit.
+pub fn bcd_to_binary(raw: u8) -> Option<u8> {
+    let (tens, units) = (raw >> 4, raw & 0x0F);
+    if tens > 9 || units > 9 {
+        return None;
+    }
+    Some(tens * 10 + units)
+}
+
+/// # BCD Field
+///
+/// A packed BCD date or time field where FFh means the field is not specified.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BcdField {
+    /// The decoded value
+    Value(u8),
+    /// FFh, the field is not specified and matches any value
+    Wildcard,
+    /// The raw value is not BCD or is outside the field's range
+    Invalid(u8),
+}
+
+impl BcdField {
+    /// Decodes a raw BCD field whose value lies within `range`
+    pub fn new(raw: u8, range: RangeInclusive<u8>) -> Self {
+        if raw == 0xFF {
+            return BcdField::Wildcard;
+        }
+        match bcd_to_binary(raw) {
+            Some(value) if range.contains(&value) => BcdField::Value(value),
+            _ => BcdField::Invalid(raw),
+        }
+    }
+
+    /// The decoded value, `None` for a wildcard or invalid field
+    pub fn value(&self) -> Option<u8> {
+        match self {
+            BcdField::Value(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for BcdField {
+    /// Two decimal digits, `*` for a wildcard and the raw hex value for an invalid field
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BcdField::Value(value) => write!(f, "{:02}", value),
+            BcdField::Wildcard => write!(f, "*"),
+            BcdField::Invalid(raw) => write!(f, "{:02X}h", raw),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_test() {
+        assert_eq!(bcd_to_binary(0x59), Some(59));
+        assert_eq!(bcd_to_binary(0x00), Some(0));
+        assert_eq!(bcd_to_binary(0x1A), None);
+        assert_eq!(bcd_to_binary(0xA1), None);
+
+        assert_eq!(BcdField::new(0x12, 1..=12), BcdField::Value(12));
+        assert_eq!(BcdField::new(0x13, 1..=12), BcdField::Invalid(0x13));
+        assert_eq!(BcdField::new(0x3A, 0..=59), BcdField::Invalid(0x3A));
+        assert_eq!(BcdField::new(0xFF, 0..=59), BcdField::Wildcard);
+        assert_eq!(BcdField::new(0x07, 0..=23).value(), Some(7));
+        assert_eq!(BcdField::Wildcard.value(), None);
+
+        assert_eq!(format!("{}", BcdField::Value(7)), "07");
+        assert_eq!(format!("{}", BcdField::Wildcard), "*");
+        assert_eq!(format!("{}", BcdField::Invalid(0x3A)), "3Ah");
+    }
+}
@@ -120,6 +120,125 @@ pub enum DefinedStruct<'a> {
     Undefined(SMBiosUnknown<'a>),
 }
 
+impl DefinedStruct<'_> {
+    /// Name of the variant, as used as the key of its serialized form
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            DefinedStruct::Information(_) => "Information",
+            DefinedStruct::SystemInformation(_) => "SystemInformation",
+            DefinedStruct::BaseBoardInformation(_) => "BaseBoardInformation",
+            DefinedStruct::SystemChassisInformation(_) => "SystemChassisInformation",
+            DefinedStruct::ProcessorInformation(_) => "ProcessorInformation",
+            DefinedStruct::MemoryControllerInformation(_) => "MemoryControllerInformation",
+            DefinedStruct::MemoryModuleInformation(_) => "MemoryModuleInformation",
+            DefinedStruct::CacheInformation(_) => "CacheInformation",
+            DefinedStruct::PortConnectorInformation(_) => "PortConnectorInformation",
+            DefinedStruct::SystemSlot(_) => "SystemSlot",
+            DefinedStruct::OnBoardDeviceInformation(_) => "OnBoardDeviceInformation",
+            DefinedStruct::OemStrings(_) => "OemStrings",
+            DefinedStruct::SystemConfigurationOptions(_) => "SystemConfigurationOptions",
+            DefinedStruct::LanguageInformation(_) => "LanguageInformation",
+            DefinedStruct::GroupAssociations(_) => "GroupAssociations",
+            DefinedStruct::EventLog(_) => "EventLog",
+            DefinedStruct::PhysicalMemoryArray(_) => "PhysicalMemoryArray",
+            DefinedStruct::MemoryDevice(_) => "MemoryDevice",
+            DefinedStruct::MemoryErrorInformation32Bit(_) => "MemoryErrorInformation32Bit",
+            DefinedStruct::MemoryArrayMappedAddress(_) => "MemoryArrayMappedAddress",
+            DefinedStruct::MemoryDeviceMappedAddress(_) => "MemoryDeviceMappedAddress",
+            DefinedStruct::BuiltInPointingDevice(_) => "BuiltInPointingDevice",
+            DefinedStruct::PortableBattery(_) => "PortableBattery",
+            DefinedStruct::SystemReset(_) => "SystemReset",
+            DefinedStruct::HardwareSecurity(_) => "HardwareSecurity",
+            DefinedStruct::SystemPowerControls(_) => "SystemPowerControls",
+            DefinedStruct::VoltageProbe(_) => "VoltageProbe",
+            DefinedStruct::CoolingDevice(_) => "CoolingDevice",
+            DefinedStruct::TemperatureProbe(_) => "TemperatureProbe",
+            DefinedStruct::ElectricalCurrentProbe(_) => "ElectricalCurrentProbe",
+            DefinedStruct::OutOfBandRemoteAccess(_) => "OutOfBandRemoteAccess",
+            DefinedStruct::BisEntryPoint(_) => "BisEntryPoint",
+            DefinedStruct::SystemBootInformation(_) => "SystemBootInformation",
+            DefinedStruct::MemoryErrorInformation64Bit(_) => "MemoryErrorInformation64Bit",
+            DefinedStruct::ManagementDevice(_) => "ManagementDevice",
+            DefinedStruct::ManagementDeviceComponent(_) => "ManagementDeviceComponent",
+            DefinedStruct::ManagementDeviceThresholdData(_) => "ManagementDeviceThresholdData",
+            DefinedStruct::MemoryChannel(_) => "MemoryChannel",
+            DefinedStruct::IpmiDeviceInformation(_) => "IpmiDeviceInformation",
+            DefinedStruct::SystemPowerSupply(_) => "SystemPowerSupply",
+            DefinedStruct::AdditionalInformation(_) => "AdditionalInformation",
+            DefinedStruct::OnboardDevicesExtendedInformation(_) => {
+                "OnboardDevicesExtendedInformation"
+            }
+            DefinedStruct::ManagementControllerHostInterface(_) => {
+                "ManagementControllerHostInterface"
+            }
+            DefinedStruct::TpmDevice(_) => "TpmDevice",
+            DefinedStruct::ProcessorAdditionalInformation(_) => "ProcessorAdditionalInformation",
+            DefinedStruct::FirmwareInventoryInformation(_) => "FirmwareInventoryInformation",
+            DefinedStruct::StringProperty(_) => "StringProperty",
+            DefinedStruct::Inactive(_) => "Inactive",
+            DefinedStruct::EndOfTable(_) => "EndOfTable",
+            DefinedStruct::Undefined(_) => "Undefined",
+        }
+    }
+
+    /// Name of the accessor reading the field which starts at `offset`,
+    /// see [SMBiosStruct::field_name]
+    pub fn field_name(&self, offset: usize) -> Option<&'static str> {
+        match self {
+            DefinedStruct::Information(data) => data.field_name(offset),
+            DefinedStruct::SystemInformation(data) => data.field_name(offset),
+            DefinedStruct::BaseBoardInformation(data) => data.field_name(offset),
+            DefinedStruct::SystemChassisInformation(data) => data.field_name(offset),
+            DefinedStruct::ProcessorInformation(data) => data.field_name(offset),
+            DefinedStruct::MemoryControllerInformation(data) => data.field_name(offset),
+            DefinedStruct::MemoryModuleInformation(data) => data.field_name(offset),
+            DefinedStruct::CacheInformation(data) => data.field_name(offset),
+            DefinedStruct::PortConnectorInformation(data) => data.field_name(offset),
+            DefinedStruct::SystemSlot(data) => data.field_name(offset),
+            DefinedStruct::OnBoardDeviceInformation(data) => data.field_name(offset),
+            DefinedStruct::OemStrings(data) => data.field_name(offset),
+            DefinedStruct::SystemConfigurationOptions(data) => data.field_name(offset),
+            DefinedStruct::LanguageInformation(data) => data.field_name(offset),
+            DefinedStruct::GroupAssociations(data) => data.field_name(offset),
+            DefinedStruct::EventLog(data) => data.field_name(offset),
+            DefinedStruct::PhysicalMemoryArray(data) => data.field_name(offset),
+            DefinedStruct::MemoryDevice(data) => data.field_name(offset),
+            DefinedStruct::MemoryErrorInformation32Bit(data) => data.field_name(offset),
+            DefinedStruct::MemoryArrayMappedAddress(data) => data.field_name(offset),
+            DefinedStruct::MemoryDeviceMappedAddress(data) => data.field_name(offset),
+            DefinedStruct::BuiltInPointingDevice(data) => data.field_name(offset),
+            DefinedStruct::PortableBattery(data) => data.field_name(offset),
+            DefinedStruct::SystemReset(data) => data.field_name(offset),
+            DefinedStruct::HardwareSecurity(data) => data.field_name(offset),
+            DefinedStruct::SystemPowerControls(data) => data.field_name(offset),
+            DefinedStruct::VoltageProbe(data) => data.field_name(offset),
+            DefinedStruct::CoolingDevice(data) => data.field_name(offset),
+            DefinedStruct::TemperatureProbe(data) => data.field_name(offset),
+            DefinedStruct::ElectricalCurrentProbe(data) => data.field_name(offset),
+            DefinedStruct::OutOfBandRemoteAccess(data) => data.field_name(offset),
+            DefinedStruct::BisEntryPoint(data) => data.field_name(offset),
+            DefinedStruct::SystemBootInformation(data) => data.field_name(offset),
+            DefinedStruct::MemoryErrorInformation64Bit(data) => data.field_name(offset),
+            DefinedStruct::ManagementDevice(data) => data.field_name(offset),
+            DefinedStruct::ManagementDeviceComponent(data) => data.field_name(offset),
+            DefinedStruct::ManagementDeviceThresholdData(data) => data.field_name(offset),
+            DefinedStruct::MemoryChannel(data) => data.field_name(offset),
+            DefinedStruct::IpmiDeviceInformation(data) => data.field_name(offset),
+            DefinedStruct::SystemPowerSupply(data) => data.field_name(offset),
+            DefinedStruct::AdditionalInformation(data) => data.field_name(offset),
+            DefinedStruct::OnboardDevicesExtendedInformation(data) => data.field_name(offset),
+            DefinedStruct::ManagementControllerHostInterface(data) => data.field_name(offset),
+            DefinedStruct::TpmDevice(data) => data.field_name(offset),
+            DefinedStruct::ProcessorAdditionalInformation(data) => data.field_name(offset),
+            DefinedStruct::FirmwareInventoryInformation(data) => data.field_name(offset),
+            DefinedStruct::StringProperty(data) => data.field_name(offset),
+            DefinedStruct::Inactive(data) => data.field_name(offset),
+            DefinedStruct::EndOfTable(data) => data.field_name(offset),
+            DefinedStruct::Undefined(_) => None,
+        }
+    }
+}
+
 impl<'a> From<&'a UndefinedStruct> for DefinedStruct<'a> {
     fn from(undefined_struct: &'a UndefinedStruct) -> Self {
         match undefined_struct.header.struct_type() {
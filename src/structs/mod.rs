@@ -1,7 +1,9 @@
+mod bcd;
 mod defined_struct;
 mod structure;
 mod types;
 
+pub use bcd::*;
 pub use defined_struct::*;
 pub use structure::*;
 pub use types::*;
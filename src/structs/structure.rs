@@ -9,9 +9,34 @@ pub trait SMBiosStruct<'a> {
     /// Example: System Information (Type 1) this is set to 1.
     const STRUCT_TYPE: u8;
 
+    /// Fields at fixed offsets read by the accessors of the implementing
+    /// SMBIOS type, as (offset, accessor name)
+    ///
+    /// Example: System Information (Type 1) lists `(0x07, "serial_number")`.
+    const FIELDS: &'static [(usize, &'static str)] = &[];
+
     /// Creates a new instance of the implementing SMBIOS type
     fn new(parts: &'a UndefinedStruct) -> Self;
 
     /// Contains the standard parts/sections of the implementing SMBIOS type.
     fn parts(&self) -> &'a UndefinedStruct;
+
+    /// Name of the accessor reading the field which starts at `offset`
+    ///
+    /// Types with fields following variable length data override this
+    /// to name the fields whose offset depends on that data.
+    fn field_name(&self, offset: usize) -> Option<&'static str> {
+        field_name_at(Self::FIELDS, offset)
+    }
+}
+
+/// Name of the field starting at `offset` in a list of (offset, accessor name)
+pub(crate) fn field_name_at(
+    fields: &[(usize, &'static str)],
+    offset: usize,
+) -> Option<&'static str> {
+    fields
+        .iter()
+        .find(|(field_offset, _)| *field_offset == offset)
+        .map(|(_, name)| *name)
 }
@@ -215,6 +215,7 @@ pub struct SMBiosAdditionalInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosAdditionalInformation<'a> {
     const STRUCT_TYPE: u8 = 40u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[(0x04, "number_of_entries")];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -277,6 +278,11 @@ mod tests {
         let parts = UndefinedStruct::new(&additional_information_bytes);
         let additional_information = SMBiosAdditionalInformation::new(&parts);
 
+        assert_eq!(
+            additional_information.field_name(0x04),
+            Some("number_of_entries")
+        );
+
         assert_eq!(*additional_information.parts().header.handle(), 0x0102);
         assert_eq!(additional_information.parts().header.length(), 0x0B);
         assert_eq!(
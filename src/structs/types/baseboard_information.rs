@@ -15,6 +15,18 @@ pub struct SMBiosBaseboardInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosBaseboardInformation<'a> {
     const STRUCT_TYPE: u8 = 2u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "manufacturer"),
+        (0x05, "product"),
+        (0x06, "version"),
+        (0x07, "serial_number"),
+        (0x08, "asset_tag"),
+        (0x09, "feature_flags"),
+        (0x0A, "location_in_chassis"),
+        (0x0B, "chassis_handle"),
+        (0x0D, "board_type"),
+        (0x0E, "number_of_contained_object_handles"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -512,5 +524,15 @@ mod tests {
 
         // debug print test
         println!("baseboard_information: {:?}", baseboard_information);
+
+        assert_eq!(baseboard_information.field_name(0x04), Some("manufacturer"));
+        assert_eq!(
+            baseboard_information.field_name(0x09),
+            Some("feature_flags")
+        );
+        assert_eq!(
+            baseboard_information.field_name(0x0E),
+            Some("number_of_contained_object_handles")
+        );
     }
 }
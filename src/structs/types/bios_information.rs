@@ -15,6 +15,23 @@ pub struct SMBiosInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosInformation<'a> {
     const STRUCT_TYPE: u8 = 0u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "vendor"),
+        (0x05, "version"),
+        (0x06, "starting_address_segment"),
+        (0x08, "release_date"),
+        (0x09, "rom_size"),
+        (0x0A, "characteristics"),
+        (0x0E, "bios_vendor_reserved_characteristics"),
+        (0x10, "system_vendor_reserved_characteristics"),
+        (0x12, "characteristics_extension0"),
+        (0x13, "characteristics_extension1"),
+        (0x14, "system_bios_major_release"),
+        (0x15, "system_bios_minor_release"),
+        (0x16, "e_c_firmware_major_release"),
+        (0x17, "e_c_firmware_minor_release"),
+        (0x18, "extended_rom_size"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -977,6 +994,13 @@ mod tests {
         let parts = UndefinedStruct::new(&struct_type0);
         let test_struct = SMBiosInformation::new(&parts);
 
+        assert_eq!(test_struct.field_name(0x04), Some("vendor"));
+        assert_eq!(
+            test_struct.field_name(0x10),
+            Some("system_vendor_reserved_characteristics")
+        );
+        assert_eq!(test_struct.field_name(0x18), Some("extended_rom_size"));
+
         assert_eq!(test_struct.vendor().to_string(), "LENOVO".to_string());
         assert_eq!(test_struct.version().to_string(), "S03KT33A".to_string());
         assert_eq!(test_struct.starting_address_segment(), Some(61440));
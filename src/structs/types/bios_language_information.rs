@@ -16,6 +16,11 @@ pub struct SMBiosBiosLanguageInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosBiosLanguageInformation<'a> {
     const STRUCT_TYPE: u8 = 13u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "number_of_installable_languages"),
+        (0x05, "flags"),
+        (Self::CURRENT_LANGUAGE_OFFSET, "current_language"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -27,6 +32,9 @@ impl<'a> SMBiosStruct<'a> for SMBiosBiosLanguageInformation<'a> {
 }
 
 impl<'a> SMBiosBiosLanguageInformation<'a> {
+    /// Offset of the Current Language field.
+    const CURRENT_LANGUAGE_OFFSET: usize = 0x15usize;
+
     /// Number of languages available
     /// Each available language has a description
     /// string. This field contains the number of strings
@@ -44,13 +52,36 @@ impl<'a> SMBiosBiosLanguageInformation<'a> {
 
     /// The currently installed language.
     pub fn current_language(&self) -> SMBiosString {
-        self.parts.get_field_string(0x15)
+        self.parts.get_field_string(Self::CURRENT_LANGUAGE_OFFSET)
     }
 
     /// Iterable collection of the installable languages.
     pub fn installable_langauges(&self) -> &SMBiosStringSet {
         &self.parts.strings
     }
+
+    /// The installable languages parsed into their language,
+    /// territory and encoding
+    ///
+    /// Strings containing '|' are parsed in the long format and
+    /// all others in the abbreviated format, whatever the
+    /// 'language_format' flag says.
+    pub fn languages(&self) -> Vec<BiosLanguage> {
+        self.parts
+            .strings
+            .iter_numbered()
+            .map(|(index, raw)| BiosLanguage::parse(index, raw))
+            .collect()
+    }
+
+    /// The currently installed language, resolved against
+    /// the installable languages
+    pub fn resolved_current_language(&self) -> Option<BiosLanguage> {
+        let index = self.parts.get_field_byte(Self::CURRENT_LANGUAGE_OFFSET)?;
+        self.languages()
+            .into_iter()
+            .find(|language| language.index == index)
+    }
 }
 
 impl fmt::Debug for SMBiosBiosLanguageInformation<'_> {
@@ -64,6 +95,11 @@ impl fmt::Debug for SMBiosBiosLanguageInformation<'_> {
             .field("flags", &self.flags())
             .field("current_language", &self.current_language())
             .field("installable_languages", &self.installable_langauges())
+            .field("languages", &self.languages())
+            .field(
+                "resolved_current_language",
+                &self.resolved_current_language(),
+            )
             .finish()
     }
 }
@@ -73,7 +109,7 @@ impl Serialize for SMBiosBiosLanguageInformation<'_> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("SMBiosBiosLanguageInformation", 5)?;
+        let mut state = serializer.serialize_struct("SMBiosBiosLanguageInformation", 7)?;
         state.serialize_field("header", &self.parts.header)?;
         state.serialize_field(
             "number_of_installable_languages",
@@ -82,10 +118,62 @@ impl Serialize for SMBiosBiosLanguageInformation<'_> {
         state.serialize_field("flags", &self.flags())?;
         state.serialize_field("current_language", &self.current_language())?;
         state.serialize_field("installable_languages", &self.installable_langauges())?;
+        state.serialize_field("languages", &self.languages())?;
+        state.serialize_field(
+            "resolved_current_language",
+            &self.resolved_current_language(),
+        )?;
         state.end()
     }
 }
 
+/// # BIOS Language
+///
+/// An installable language of [SMBiosBiosLanguageInformation].
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct BiosLanguage {
+    /// String number of the language, starting at 1
+    pub index: u8,
+    /// The language string, e.g. "en|US|iso8859-1" or "enUS"
+    pub text: String,
+    /// ISO 639 language code, e.g. "en"
+    pub language: Option<String>,
+    /// ISO 3166 territory code, e.g. "US"
+    pub territory: Option<String>,
+    /// Encoding method, e.g. "iso8859-1"
+    ///
+    /// Only the long format names an encoding.
+    pub encoding: Option<String>,
+}
+
+impl BiosLanguage {
+    fn parse(index: u8, raw: &[u8]) -> Self {
+        let text = String::from_utf8_lossy(raw).trim().to_string();
+        let non_empty = |part: &str| Some(part.trim().to_string()).filter(|part| !part.is_empty());
+
+        let (language, territory, encoding) = if text.contains('|') {
+            let mut parts = text.splitn(3, '|');
+            (
+                parts.next().and_then(non_empty),
+                parts.next().and_then(non_empty),
+                parts.next().and_then(non_empty),
+            )
+        } else if text.is_ascii() && text.len() == 4 {
+            (non_empty(&text[..2]), non_empty(&text[2..]), None)
+        } else {
+            (non_empty(&text), None, None)
+        };
+
+        BiosLanguage {
+            index,
+            text,
+            language,
+            territory,
+            encoding,
+        }
+    }
+}
+
 /// # Language Format
 #[derive(Serialize, Debug)]
 pub enum LanguageFormat {
@@ -207,7 +295,62 @@ mod tests {
         assert_eq!(third_string, Some("ja|JP|unicode".to_string()));
         assert!(string_iterator.next().is_none());
 
+        // parsed language tests
+        let languages = bios_language_information.languages();
+        assert_eq!(languages.len(), 3);
+        assert_eq!(
+            languages[1],
+            BiosLanguage {
+                index: 2,
+                text: "hr|HR|iso8859-2".to_string(),
+                language: Some("hr".to_string()),
+                territory: Some("HR".to_string()),
+                encoding: Some("iso8859-2".to_string()),
+            }
+        );
+        assert_eq!(
+            bios_language_information
+                .resolved_current_language()
+                .map(|language| language.index),
+            Some(1)
+        );
+
         // debug print test
         println!("bios_language_information: {:?}", bios_language_information);
+
+        assert_eq!(
+            bios_language_information.field_name(0x04),
+            Some("number_of_installable_languages")
+        );
+        assert_eq!(
+            bios_language_information.field_name(0x15),
+            Some("current_language")
+        );
+    }
+
+    #[test]
+    fn test_abbreviated_languages() {
+        let mut bios_language_information_bytes = vec![
+            0x0Du8, 0x16, 0x21, 0x00, 0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+        ];
+        bios_language_information_bytes.extend_from_slice(b"enUS\0frCA\0\0");
+
+        let parts = UndefinedStruct::new(&bios_language_information_bytes);
+        let bios_language_information = SMBiosBiosLanguageInformation::new(&parts);
+
+        let current = bios_language_information
+            .resolved_current_language()
+            .unwrap();
+        assert_eq!(current.text, "frCA");
+        assert_eq!(current.language.as_deref(), Some("fr"));
+        assert_eq!(current.territory.as_deref(), Some("CA"));
+        assert_eq!(current.encoding, None);
+
+        assert_eq!(BiosLanguage::parse(1, b"en||").territory, None);
+        assert_eq!(
+            BiosLanguage::parse(1, b"eng").language.as_deref(),
+            Some("eng")
+        );
     }
 }
@@ -39,6 +39,11 @@ pub struct SMBiosBisEntryPoint<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosBisEntryPoint<'a> {
     const STRUCT_TYPE: u8 = 31u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "checksum"),
+        (0x08, "bis_entry_16"),
+        (0x0C, "bis_entry_32"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
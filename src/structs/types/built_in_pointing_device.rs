@@ -19,6 +19,11 @@ pub struct SMBiosBuiltInPointingDevice<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosBuiltInPointingDevice<'a> {
     const STRUCT_TYPE: u8 = 21u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "device_type"),
+        (0x05, "interface"),
+        (0x06, "number_of_buttons"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -295,5 +300,8 @@ mod tests {
             PointingDeviceInterface::PS2
         );
         assert_eq!(test_struct.number_of_buttons(), Some(3));
+
+        assert_eq!(test_struct.field_name(0x04), Some("device_type"));
+        assert_eq!(test_struct.field_name(0x06), Some("number_of_buttons"));
     }
 }
@@ -20,6 +20,20 @@ pub struct SMBiosCacheInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosCacheInformation<'a> {
     const STRUCT_TYPE: u8 = 7u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "socket_designation"),
+        (0x05, "cache_configuration"),
+        (0x07, "maximum_cache_size"),
+        (0x09, "installed_size"),
+        (0x0B, "supported_sram_type"),
+        (0x0D, "current_sram_type"),
+        (0x0F, "cache_speed"),
+        (0x10, "error_correction_type"),
+        (0x11, "system_cache_type"),
+        (0x12, "associativity"),
+        (0x13, "maximum_cache_size_2"),
+        (0x17, "installed_cache_size_2"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -111,6 +125,35 @@ impl<'a> SMBiosCacheInformation<'a> {
             .get_field_dword(0x17)
             .map(|raw| CacheMemorySize::from(raw))
     }
+
+    /// Maximum size that can be installed, in bytes
+    ///
+    /// Resolves the Maximum Cache Size field, following it to the
+    /// Maximum Cache Size 2 field when it is FFFFh (2048 MB or larger).
+    pub fn effective_maximum_cache_size(&self) -> Option<u64> {
+        resolve_cache_size(self.maximum_cache_size(), self.maximum_cache_size_2())
+    }
+
+    /// Installed size, in bytes; 0 if no cache is installed
+    ///
+    /// Resolves the Installed Size field, following it to the
+    /// Installed Cache Size 2 field when it is FFFFh (2048 MB or larger).
+    pub fn effective_installed_cache_size(&self) -> Option<u64> {
+        resolve_cache_size(self.installed_size(), self.installed_cache_size_2())
+    }
+}
+
+fn resolve_cache_size(
+    size: Option<CacheMemorySize>,
+    size_2: Option<CacheMemorySize>,
+) -> Option<u64> {
+    match size? {
+        CacheMemorySize::Kilobytes(size) => Some(size * 1024),
+        CacheMemorySize::SeeCacheSize2 => match size_2? {
+            CacheMemorySize::Kilobytes(size) => Some(size * 1024),
+            CacheMemorySize::SeeCacheSize2 => None,
+        },
+    }
 }
 
 impl fmt::Debug for SMBiosCacheInformation<'_> {
@@ -713,8 +756,13 @@ mod tests {
 
         let cache_size = test_struct.maximum_cache_size().unwrap();
         assert_eq!(cache_size, CacheMemorySize::Kilobytes(384));
+        assert_eq!(test_struct.effective_maximum_cache_size(), Some(384 * 1024));
 
         println!("{:?}", test_struct);
+
+        assert_eq!(test_struct.field_name(0x04), Some("socket_designation"));
+        assert_eq!(test_struct.field_name(0x0F), Some("cache_speed"));
+        assert_eq!(test_struct.field_name(0x17), Some("installed_cache_size_2"));
     }
 
     #[test]
@@ -736,4 +784,30 @@ mod tests {
             CacheMemorySize::Kilobytes(2u64.pow(37) - 64)
         );
     }
+
+    #[test]
+    fn effective_size_test() {
+        // 4 GB maximum cache size only described by Maximum Cache Size 2
+        let struct_type7 = vec![
+            0x07, 0x1B, 0x05, 0x00, 0x00, 0x82, 0x01, 0xFF, 0xFF, 0x00, 0x90, 0x20, 0x00, 0x20,
+            0x00, 0x00, 0x05, 0x05, 0x08, 0x00, 0x00, 0x01, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type7);
+        let test_struct = SMBiosCacheInformation::new(&parts);
+        assert_eq!(
+            test_struct.maximum_cache_size(),
+            Some(CacheMemorySize::SeeCacheSize2)
+        );
+        assert_eq!(
+            test_struct.effective_maximum_cache_size(),
+            Some(0x10000 * 64 * 1024)
+        );
+        // Installed Size is used as is; Installed Cache Size 2 is 0
+        assert_eq!(
+            test_struct.effective_installed_cache_size(),
+            Some(256 * 1024 * 1024)
+        );
+    }
 }
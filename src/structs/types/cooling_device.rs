@@ -16,6 +16,14 @@ pub struct SMBiosCoolingDevice<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosCoolingDevice<'a> {
     const STRUCT_TYPE: u8 = 27u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "temperature_probe_handle"),
+        (0x06, "device_type_and_status"),
+        (0x07, "cooling_unit_group"),
+        (0x08, "oem_defined"),
+        (0x0C, "nominal_speed"),
+        (0x0E, "description"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -281,5 +289,12 @@ mod tests {
             test_struct.description().to_string(),
             "Cooling Dev 1".to_string()
         );
+
+        assert_eq!(
+            test_struct.field_name(0x04),
+            Some("temperature_probe_handle")
+        );
+        assert_eq!(test_struct.field_name(0x08), Some("oem_defined"));
+        assert_eq!(test_struct.field_name(0x0E), Some("description"));
     }
 }
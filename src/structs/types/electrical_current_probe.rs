@@ -16,6 +16,17 @@ pub struct SMBiosElectricalCurrentProbe<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosElectricalCurrentProbe<'a> {
     const STRUCT_TYPE: u8 = 29u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "description"),
+        (0x05, "location_and_status"),
+        (0x06, "maximum_value"),
+        (0x08, "minimum_value"),
+        (0x0A, "resolution"),
+        (0x0C, "tolerance"),
+        (0x0E, "accuracy"),
+        (0x10, "oem_defined"),
+        (0x14, "nominal_value"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -346,5 +357,9 @@ mod tests {
             ProbeAmperage::Milliamps(_) => panic!("expected unknown"),
             ProbeAmperage::Unknown => (),
         }
+
+        assert_eq!(test_struct.field_name(0x04), Some("description"));
+        assert_eq!(test_struct.field_name(0x0A), Some("resolution"));
+        assert_eq!(test_struct.field_name(0x14), Some("nominal_value"));
     }
 }
@@ -26,6 +26,20 @@ pub struct SMBiosFirmwareInventoryInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosFirmwareInventoryInformation<'a> {
     const STRUCT_TYPE: u8 = 45u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "firmware_component_name"),
+        (0x05, "firmware_version"),
+        (0x06, "version_format"),
+        (0x07, "firmware_id"),
+        (0x08, "firmware_id_format"),
+        (0x09, "release_date"),
+        (0x0A, "manufacturer"),
+        (0x0B, "lowest_supported_firmware_version"),
+        (0x0C, "image_size"),
+        (0x14, "characteristics"),
+        (0x16, "state"),
+        (0x17, "number_of_associated_components"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -777,5 +791,18 @@ mod tests {
             "firmware_inventory_information: {:?}",
             firmware_inventory_information
         );
+
+        assert_eq!(
+            firmware_inventory_information.field_name(0x04),
+            Some("firmware_component_name")
+        );
+        assert_eq!(
+            firmware_inventory_information.field_name(0x0A),
+            Some("manufacturer")
+        );
+        assert_eq!(
+            firmware_inventory_information.field_name(0x17),
+            Some("number_of_associated_components")
+        );
     }
 }
@@ -18,6 +18,7 @@ pub struct SMBiosGroupAssociations<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosGroupAssociations<'a> {
     const STRUCT_TYPE: u8 = 14u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[(0x04, "group_name")];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -253,5 +254,7 @@ mod tests {
         let first_item = iterator.next().unwrap();
         assert_eq!(first_item.struct_type(), Some(221));
         assert_eq!(*first_item.item_handle().unwrap(), 91);
+
+        assert_eq!(test_struct.field_name(0x04), Some("group_name"));
     }
 }
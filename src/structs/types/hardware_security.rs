@@ -15,6 +15,7 @@ pub struct SMBiosHardwareSecurity<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosHardwareSecurity<'a> {
     const STRUCT_TYPE: u8 = 24u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[(0x04, "hardware_security_settings")];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -172,5 +173,10 @@ mod tests {
             test_struct.hardware_security_settings(),
             Some(HardwareSecuritySettings::from(22))
         );
+
+        assert_eq!(
+            test_struct.field_name(0x04),
+            Some("hardware_security_settings")
+        );
     }
 }
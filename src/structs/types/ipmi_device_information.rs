@@ -17,6 +17,15 @@ pub struct SMBiosIpmiDeviceInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosIpmiDeviceInformation<'a> {
     const STRUCT_TYPE: u8 = 38u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "interface_type"),
+        (0x05, "ipmi_specification_revision"),
+        (0x06, "i2c_target_address"),
+        (0x07, "nvstorage_device_address"),
+        (0x08, "base_address"),
+        (0x10, "base_address_modifier"),
+        (0x11, "interrupt_number"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -374,5 +383,12 @@ mod tests {
             base_address_modifier.interrupt_trigger_mode,
             InterruptTriggerMode::Edge
         );
+
+        assert_eq!(test_struct.field_name(0x04), Some("interface_type"));
+        assert_eq!(
+            test_struct.field_name(0x07),
+            Some("nvstorage_device_address")
+        );
+        assert_eq!(test_struct.field_name(0x11), Some("interrupt_number"));
     }
 }
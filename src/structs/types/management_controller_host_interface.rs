@@ -1,6 +1,9 @@
-use crate::{SMBiosStruct, UndefinedStruct};
+use crate::core::Handle;
+use crate::{field_name_at, SMBiosStruct, SystemUuid, UndefinedStruct};
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
+use std::convert::TryInto;
 use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::ops::Deref;
 
 /// # Management Controller Host Interface (Type 42)
@@ -25,6 +28,14 @@ pub struct SMBiosManagementControllerHostInterface<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosManagementControllerHostInterface<'a> {
     const STRUCT_TYPE: u8 = 42u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (Self::INTERFACE_TYPE_OFFSET, "interface_type"),
+        (
+            Self::SPECIFIC_DATA_LENGTH_OFFSET,
+            "interface_type_specific_data_length",
+        ),
+        (Self::SPECIFIC_DATA_OFFSET, "interface_type_specific_data"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -33,6 +44,18 @@ impl<'a> SMBiosStruct<'a> for SMBiosManagementControllerHostInterface<'a> {
     fn parts(&self) -> &'a UndefinedStruct {
         self.parts
     }
+
+    fn field_name(&self, offset: usize) -> Option<&'static str> {
+        field_name_at(Self::FIELDS, offset).or_else(|| {
+            // The protocol records follow the interface type specific data
+            let records_offset = self.protocol_records_offset()?;
+            if offset + 1 == records_offset {
+                return Some("number_of_protocol_records");
+            }
+            self.protocol_record_iterator()
+                .find_map(|record| record.field_name(offset))
+        })
+    }
 }
 
 impl<'a> SMBiosManagementControllerHostInterface<'a> {
@@ -89,6 +112,18 @@ impl<'a> SMBiosManagementControllerHostInterface<'a> {
     pub fn protocol_record_iterator(&self) -> ProtocolRecordIterator<'_> {
         ProtocolRecordIterator::new(self)
     }
+
+    /// Network host interface device descriptor decoded per DSP0270
+    ///
+    /// Present when 'interface_type' is [HostInterfaceType::NetworkHostInterface].
+    pub fn network_interface(&self) -> Option<NetworkInterfaceDevice> {
+        match self.interface_type()?.value {
+            HostInterfaceType::NetworkHostInterface => {
+                NetworkInterfaceDevice::new(self.interface_type_specific_data()?, self.parts)
+            }
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Debug for SMBiosManagementControllerHostInterface<'_> {
@@ -111,6 +146,7 @@ impl fmt::Debug for SMBiosManagementControllerHostInterface<'_> {
             &self.number_of_protocol_records(),
         )
         .field("protocol_record_iterator", &self.protocol_record_iterator())
+        .field("network_interface", &self.network_interface())
         .finish()
     }
 }
@@ -121,7 +157,7 @@ impl Serialize for SMBiosManagementControllerHostInterface<'_> {
         S: Serializer,
     {
         let mut state =
-            serializer.serialize_struct("SMBiosManagementControllerHostInterface", 6)?;
+            serializer.serialize_struct("SMBiosManagementControllerHostInterface", 7)?;
         state.serialize_field("header", &self.parts.header)?;
         state.serialize_field("interface_type", &self.interface_type())?;
         state.serialize_field(
@@ -137,6 +173,7 @@ impl Serialize for SMBiosManagementControllerHostInterface<'_> {
             &self.number_of_protocol_records(),
         )?;
         state.serialize_field("protocol_record_iterator", &self.protocol_record_iterator())?;
+        state.serialize_field("network_interface", &self.network_interface())?;
         state.end()
     }
 }
@@ -388,6 +425,17 @@ impl<'a> ProtocolRecord<'a> {
         }
     }
 
+    /// Name of the accessor reading the field of this record at
+    /// `offset` within the structure
+    fn field_name(&self, offset: usize) -> Option<&'static str> {
+        match offset.checked_sub(self.entry_offset)? {
+            Self::PROTOCOL_TYPE_OFFSET => Some("protocol_type"),
+            Self::SPECIFIC_DATA_LENGTH_OFFSET => Some("protocol_type_specific_data_length"),
+            Self::SPECIFIC_DATA_OFFSET => Some("protocol_type_specific_data"),
+            _ => None,
+        }
+    }
+
     /// Protocol Type
     pub fn protocol_type(&self) -> Option<HostProtocolTypeData> {
         self.host_interface
@@ -413,6 +461,18 @@ impl<'a> ProtocolRecord<'a> {
                     .get_field_data(start_index, start_index + length as usize)
             })
     }
+
+    /// Redfish over IP protocol data decoded per DSP0270
+    ///
+    /// Present when 'protocol_type' is [HostProtocolType::RedfishOverIP].
+    pub fn redfish_over_ip(&self) -> Option<RedfishOverIp> {
+        match self.protocol_type()?.value {
+            HostProtocolType::RedfishOverIP => {
+                RedfishOverIp::new(self.protocol_type_specific_data()?)
+            }
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Debug for ProtocolRecord<'_> {
@@ -427,6 +487,7 @@ impl fmt::Debug for ProtocolRecord<'_> {
                 "protocol_type_specific_data",
                 &self.protocol_type_specific_data(),
             )
+            .field("redfish_over_ip", &self.redfish_over_ip())
             .finish()
     }
 }
@@ -436,7 +497,7 @@ impl Serialize for ProtocolRecord<'_> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("ProtocolRecord", 3)?;
+        let mut state = serializer.serialize_struct("ProtocolRecord", 4)?;
         state.serialize_field("protocol_type", &self.protocol_type())?;
         state.serialize_field(
             "protocol_type_specific_data_length",
@@ -446,6 +507,7 @@ impl Serialize for ProtocolRecord<'_> {
             "protocol_type_specific_data",
             &self.protocol_type_specific_data(),
         )?;
+        state.serialize_field("redfish_over_ip", &self.redfish_over_ip())?;
         state.end()
     }
 }
@@ -561,6 +623,338 @@ impl<'a> Serialize for ProtocolRecordIterator<'a> {
     }
 }
 
+/// # Network Host Interface Device
+///
+/// Device descriptor of a Redfish network host interface, as defined by
+/// [DSP0270](https://www.dmtf.org/sites/default/files/standards/documents/DSP0270_1.3.0.pdf).
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub enum NetworkInterfaceDevice {
+    /// USB network interface (device types 02h and 04h)
+    Usb(UsbNetworkInterface),
+    /// PCI/PCIe network interface (device types 03h and 05h)
+    Pci(PciNetworkInterface),
+    /// OEM-defined (device types 80h-FFh) or reserved device type
+    Other {
+        /// Device type
+        device_type: u8,
+        /// Device descriptor data
+        data: Vec<u8>,
+    },
+}
+
+impl NetworkInterfaceDevice {
+    const USB: u8 = 0x02;
+    const PCI: u8 = 0x03;
+    const USB_V2: u8 = 0x04;
+    const PCI_V2: u8 = 0x05;
+
+    /// Offset of the device descriptor data within the interface type specific data
+    const DESCRIPTOR_OFFSET: usize = 1usize;
+
+    fn new(data: &[u8], parts: &UndefinedStruct) -> Option<Self> {
+        let device_type = *data.first()?;
+        let descriptor = &data[Self::DESCRIPTOR_OFFSET..];
+        let device = match device_type {
+            Self::USB => NetworkInterfaceDevice::Usb(UsbNetworkInterface {
+                vendor_id: read_word(descriptor, 0x00)?,
+                product_id: read_word(descriptor, 0x02)?,
+                // USB string descriptor: bLength, bDescriptorType, UTF-16LE string
+                serial_number: descriptor.get(0x04).and_then(|&length| {
+                    let units: Vec<u16> = descriptor
+                        .get(0x06..0x04 + length as usize)?
+                        .chunks_exact(2)
+                        .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+                        .collect();
+                    Some(String::from_utf16_lossy(&units))
+                }),
+                mac_address: None,
+                characteristics: None,
+                credential_bootstrapping_handle: None,
+            }),
+            Self::USB_V2 => NetworkInterfaceDevice::Usb(UsbNetworkInterface {
+                vendor_id: read_word(descriptor, 0x01)?,
+                product_id: read_word(descriptor, 0x03)?,
+                // String number in the structure's string set
+                serial_number: parts
+                    .get_field_string(
+                        SMBiosManagementControllerHostInterface::SPECIFIC_DATA_OFFSET
+                            + Self::DESCRIPTOR_OFFSET
+                            + 0x05,
+                    )
+                    .to_utf8_lossy(),
+                mac_address: read_mac_address(descriptor, 0x06),
+                characteristics: read_word(descriptor, 0x0C),
+                credential_bootstrapping_handle: read_word(descriptor, 0x0E).map(Handle),
+            }),
+            Self::PCI => NetworkInterfaceDevice::Pci(PciNetworkInterface {
+                vendor_id: read_word(descriptor, 0x00)?,
+                device_id: read_word(descriptor, 0x02)?,
+                subsystem_vendor_id: read_word(descriptor, 0x04)?,
+                subsystem_id: read_word(descriptor, 0x06)?,
+                mac_address: None,
+                segment_group_number: None,
+                bus_number: None,
+                device_function_number: None,
+                characteristics: None,
+                credential_bootstrapping_handle: None,
+            }),
+            Self::PCI_V2 => NetworkInterfaceDevice::Pci(PciNetworkInterface {
+                vendor_id: read_word(descriptor, 0x01)?,
+                device_id: read_word(descriptor, 0x03)?,
+                subsystem_vendor_id: read_word(descriptor, 0x05)?,
+                subsystem_id: read_word(descriptor, 0x07)?,
+                mac_address: read_mac_address(descriptor, 0x09),
+                segment_group_number: read_word(descriptor, 0x0F),
+                bus_number: descriptor.get(0x11).copied(),
+                device_function_number: descriptor.get(0x12).copied(),
+                characteristics: read_word(descriptor, 0x13),
+                credential_bootstrapping_handle: read_word(descriptor, 0x15).map(Handle),
+            }),
+            _ => NetworkInterfaceDevice::Other {
+                device_type,
+                data: descriptor.to_vec(),
+            },
+        };
+        Some(device)
+    }
+}
+
+/// # USB Network Interface
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct UsbNetworkInterface {
+    /// USB vendor ID (idVendor)
+    pub vendor_id: u16,
+    /// USB product ID (idProduct)
+    pub product_id: u16,
+    /// Serial number of the device
+    pub serial_number: Option<String>,
+    /// MAC address of the host side of the interface (v2 descriptor only)
+    pub mac_address: Option<MacAddress>,
+    /// Characteristics, bit 0 set if credential bootstrapping via IPMI is supported (v2 descriptor only)
+    pub characteristics: Option<u16>,
+    /// Handle of the Type 42 structure for credential bootstrapping (v2 descriptor only)
+    pub credential_bootstrapping_handle: Option<Handle>,
+}
+
+/// # PCI/PCIe Network Interface
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct PciNetworkInterface {
+    /// PCI vendor ID
+    pub vendor_id: u16,
+    /// PCI device ID
+    pub device_id: u16,
+    /// PCI subsystem vendor ID
+    pub subsystem_vendor_id: u16,
+    /// PCI subsystem ID
+    pub subsystem_id: u16,
+    /// MAC address of the host side of the interface (v2 descriptor only)
+    pub mac_address: Option<MacAddress>,
+    /// PCI segment group number (v2 descriptor only)
+    pub segment_group_number: Option<u16>,
+    /// PCI bus number (v2 descriptor only)
+    pub bus_number: Option<u8>,
+    /// Bits 7:3 device number and bits 2:0 function number (v2 descriptor only)
+    pub device_function_number: Option<u8>,
+    /// Characteristics, bit 0 set if credential bootstrapping via IPMI is supported (v2 descriptor only)
+    pub characteristics: Option<u16>,
+    /// Handle of the Type 42 structure for credential bootstrapping (v2 descriptor only)
+    pub credential_bootstrapping_handle: Option<Handle>,
+}
+
+/// # MAC Address
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct MacAddress(pub [u8; 6]);
+
+impl fmt::Display for MacAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Example output: "3c:ec:ef:7a:00:01"
+        let octets: Vec<String> = self
+            .0
+            .iter()
+            .map(|octet| format!("{:02x}", octet))
+            .collect();
+        write!(f, "{}", octets.join(":"))
+    }
+}
+
+impl fmt::Debug for MacAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", &self)
+    }
+}
+
+impl Serialize for MacAddress {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(format!("{}", self).as_str())
+    }
+}
+
+/// # Redfish over IP Protocol Data
+///
+/// Protocol type specific data of a Redfish over IP protocol record, as
+/// defined by [DSP0270](https://www.dmtf.org/sites/default/files/standards/documents/DSP0270_1.3.0.pdf).
+#[derive(Serialize, Debug)]
+pub struct RedfishOverIp {
+    /// UUID of the Redfish service
+    pub service_uuid: SystemUuid,
+    /// How the host IP address is assigned
+    pub host_ip_assignment_type: IpAssignmentTypeData,
+    /// Host IP address, `None` if the format is unknown
+    pub host_ip_address: Option<IpAddr>,
+    /// Host IP subnet mask, `None` if the format is unknown
+    pub host_ip_mask: Option<IpAddr>,
+    /// How the Redfish service IP address is discovered
+    pub service_ip_discovery_type: IpAssignmentTypeData,
+    /// Redfish service IP address, `None` if the format is unknown
+    pub service_ip_address: Option<IpAddr>,
+    /// Redfish service IP subnet mask, `None` if the format is unknown
+    pub service_ip_mask: Option<IpAddr>,
+    /// Redfish service IP port
+    pub service_ip_port: u16,
+    /// Redfish service VLAN ID, 0 if VLANs are not used
+    pub service_vlan_id: u32,
+    /// Redfish service hostname
+    pub service_hostname: Option<String>,
+}
+
+impl RedfishOverIp {
+    fn new(data: &[u8]) -> Option<Self> {
+        let service_uuid: &[u8; 0x10] = data.get(0x00..0x10)?.try_into().ok()?;
+        let hostname_length = *data.get(0x5A)? as usize;
+        Some(RedfishOverIp {
+            service_uuid: SystemUuid::from(service_uuid),
+            host_ip_assignment_type: IpAssignmentTypeData::from(*data.get(0x10)?),
+            host_ip_address: read_ip_address(data, 0x11, 0x12),
+            host_ip_mask: read_ip_address(data, 0x11, 0x22),
+            service_ip_discovery_type: IpAssignmentTypeData::from(*data.get(0x32)?),
+            service_ip_address: read_ip_address(data, 0x33, 0x34),
+            service_ip_mask: read_ip_address(data, 0x33, 0x44),
+            service_ip_port: read_word(data, 0x54)?,
+            service_vlan_id: u32::from_le_bytes(data.get(0x56..0x5A)?.try_into().ok()?),
+            service_hostname: data
+                .get(0x5B..0x5B + hostname_length)
+                .map(|hostname| {
+                    String::from_utf8_lossy(hostname)
+                        .trim_end_matches('\0')
+                        .to_string()
+                })
+                .filter(|hostname| !hostname.is_empty()),
+        })
+    }
+}
+
+/// # IP Assignment Type Data
+pub struct IpAssignmentTypeData {
+    /// Raw value
+    ///
+    /// _raw_ is most useful when _value_ is None.
+    /// This is most likely to occur when the standard was updated but
+    /// this library code has not been updated to match the current
+    /// standard.
+    pub raw: u8,
+    /// The contained [IpAssignmentType] value
+    pub value: IpAssignmentType,
+}
+
+impl fmt::Debug for IpAssignmentTypeData {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<IpAssignmentTypeData>())
+            .field("raw", &self.raw)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl Serialize for IpAssignmentTypeData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("IpAssignmentTypeData", 2)?;
+        state.serialize_field("raw", &self.raw)?;
+        state.serialize_field("value", &self.value)?;
+        state.end()
+    }
+}
+
+impl fmt::Display for IpAssignmentTypeData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            IpAssignmentType::None => write!(f, "{}", &self.raw),
+            _ => write!(f, "{:?}", &self.value),
+        }
+    }
+}
+
+impl Deref for IpAssignmentTypeData {
+    type Target = IpAssignmentType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+/// # IP Assignment Type
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub enum IpAssignmentType {
+    /// Unknown
+    Unknown,
+    /// Static
+    Static,
+    /// DHCP
+    Dhcp,
+    /// AutoConfigure
+    AutoConfigure,
+    /// HostSelected
+    HostSelected,
+    /// A value unknown to this standard, check the raw value
+    None,
+}
+
+impl From<u8> for IpAssignmentTypeData {
+    fn from(raw: u8) -> Self {
+        IpAssignmentTypeData {
+            value: match raw {
+                0x00 => IpAssignmentType::Unknown,
+                0x01 => IpAssignmentType::Static,
+                0x02 => IpAssignmentType::Dhcp,
+                0x03 => IpAssignmentType::AutoConfigure,
+                0x04 => IpAssignmentType::HostSelected,
+                _ => IpAssignmentType::None,
+            },
+            raw,
+        }
+    }
+}
+
+fn read_word(data: &[u8], offset: usize) -> Option<u16> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_mac_address(data: &[u8], offset: usize) -> Option<MacAddress> {
+    data.get(offset..offset + 6)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(MacAddress)
+}
+
+/// Reads a 16 byte IP address field whose format (01h IPv4, 02h IPv6) is given at `format_offset`
+///
+/// IPv4 addresses occupy the first four bytes of the field.
+fn read_ip_address(data: &[u8], format_offset: usize, offset: usize) -> Option<IpAddr> {
+    let address: [u8; 0x10] = data.get(offset..offset + 0x10)?.try_into().ok()?;
+    match data.get(format_offset)? {
+        0x01 => Some(IpAddr::V4(Ipv4Addr::new(
+            address[0], address[1], address[2], address[3],
+        ))),
+        0x02 => Some(IpAddr::V6(Ipv6Addr::from(address))),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -593,5 +987,126 @@ mod tests {
         let second = iterator.next().unwrap();
         assert_eq!(*second.protocol_type().unwrap(), HostProtocolType::Ipmi);
         assert!(iterator.next().is_none());
+
+        // The protocol records follow the 4 bytes of interface type specific data
+        assert_eq!(test_struct.field_name(0x04), Some("interface_type"));
+        assert_eq!(
+            test_struct.field_name(0x06),
+            Some("interface_type_specific_data")
+        );
+        assert_eq!(
+            test_struct.field_name(0x0A),
+            Some("number_of_protocol_records")
+        );
+        assert_eq!(test_struct.field_name(0x0B), Some("protocol_type"));
+        assert_eq!(
+            test_struct.field_name(0x0C),
+            Some("protocol_type_specific_data_length")
+        );
+        assert_eq!(
+            test_struct.field_name(0x0D),
+            Some("protocol_type_specific_data")
+        );
+        assert_eq!(test_struct.field_name(0x0E), Some("protocol_type"));
+        assert_eq!(test_struct.field_name(0x11), None);
+    }
+
+    #[test]
+    fn redfish_host_interface_test() {
+        let mut struct_type42 = vec![42u8, 0x00, 0x25, 0x00, 0x40];
+        // USB v2 network interface
+        let descriptor = [
+            0x04, // device type
+            0x11, // length
+            0x6B, 0x04, // idVendor
+            0x01, 0xFF, // idProduct
+            0x01, // serial number string
+            0x3C, 0xEC, 0xEF, 0x7A, 0x00, 0x01, // MAC address
+            0x01, 0x00, // characteristics
+            0x26, 0x00, // credential bootstrapping handle
+        ];
+        struct_type42.push(descriptor.len() as u8);
+        struct_type42.extend_from_slice(&descriptor);
+        struct_type42.push(1); // number of protocol records
+
+        let mut redfish = vec![0u8; 0x5B];
+        redfish[0x00..0x10].copy_from_slice(&[
+            0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD,
+            0xEE, 0xFF,
+        ]);
+        redfish[0x10] = 0x01; // host static
+        redfish[0x11] = 0x01; // IPv4
+        redfish[0x12..0x16].copy_from_slice(&[169, 254, 0, 2]);
+        redfish[0x22..0x26].copy_from_slice(&[255, 255, 0, 0]);
+        redfish[0x32] = 0x01; // service static
+        redfish[0x33] = 0x01; // IPv4
+        redfish[0x34..0x38].copy_from_slice(&[169, 254, 0, 1]);
+        redfish[0x44..0x48].copy_from_slice(&[255, 255, 0, 0]);
+        redfish[0x54..0x56].copy_from_slice(&443u16.to_le_bytes());
+        redfish[0x56..0x5A].copy_from_slice(&7u32.to_le_bytes());
+        redfish[0x5A] = 3;
+        redfish.extend_from_slice(b"bmc");
+        struct_type42.push(0x04); // Redfish over IP
+        struct_type42.push(redfish.len() as u8);
+        struct_type42.extend_from_slice(&redfish);
+
+        struct_type42[1] = struct_type42.len() as u8;
+        struct_type42.extend_from_slice(b"SN0001\0\0");
+
+        let parts = UndefinedStruct::new(&struct_type42);
+        let test_struct = SMBiosManagementControllerHostInterface::new(&parts);
+
+        assert_eq!(
+            test_struct.network_interface(),
+            Some(NetworkInterfaceDevice::Usb(UsbNetworkInterface {
+                vendor_id: 0x046B,
+                product_id: 0xFF01,
+                serial_number: Some("SN0001".to_string()),
+                mac_address: Some(MacAddress([0x3C, 0xEC, 0xEF, 0x7A, 0x00, 0x01])),
+                characteristics: Some(1),
+                credential_bootstrapping_handle: Some(Handle(0x26)),
+            }))
+        );
+
+        let record = test_struct.protocol_record_iterator().next().unwrap();
+        let redfish = record.redfish_over_ip().unwrap();
+        assert_eq!(
+            redfish.service_uuid.to_string(),
+            "00112233-4455-6677-8899-aabbccddeeff"
+        );
+        assert_eq!(*redfish.host_ip_assignment_type, IpAssignmentType::Static);
+        assert_eq!(
+            redfish.host_ip_address,
+            Some(IpAddr::V4(Ipv4Addr::new(169, 254, 0, 2)))
+        );
+        assert_eq!(
+            redfish.host_ip_mask,
+            Some(IpAddr::V4(Ipv4Addr::new(255, 255, 0, 0)))
+        );
+        assert_eq!(
+            redfish.service_ip_address,
+            Some(IpAddr::V4(Ipv4Addr::new(169, 254, 0, 1)))
+        );
+        assert_eq!(redfish.service_ip_port, 443);
+        assert_eq!(redfish.service_vlan_id, 7);
+        assert_eq!(redfish.service_hostname.as_deref(), Some("bmc"));
+
+        // PCI v1 network interface
+        let struct_type42 = vec![
+            42u8, 0x10, 0x25, 0x00, 0x40, 0x09, 0x03, 0x86, 0x80, 0x33, 0x15, 0xD9, 0x15, 0x4D,
+            0x15, 0x00, 0x00, 0x00,
+        ];
+        let parts = UndefinedStruct::new(&struct_type42);
+        let test_struct = SMBiosManagementControllerHostInterface::new(&parts);
+        match test_struct.network_interface().unwrap() {
+            NetworkInterfaceDevice::Pci(pci) => {
+                assert_eq!(pci.vendor_id, 0x8086);
+                assert_eq!(pci.device_id, 0x1533);
+                assert_eq!(pci.subsystem_vendor_id, 0x15D9);
+                assert_eq!(pci.subsystem_id, 0x154D);
+                assert_eq!(pci.mac_address, None);
+            }
+            other => panic!("expected PCI interface, got {:?}", other),
+        }
     }
 }
@@ -17,6 +17,12 @@ pub struct SMBiosManagementDevice<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosManagementDevice<'a> {
     const STRUCT_TYPE: u8 = 34u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "description"),
+        (0x05, "device_type"),
+        (0x06, "address"),
+        (0x0A, "address_type"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -296,5 +302,8 @@ mod tests {
             *test_struct.address_type().unwrap(),
             ManagementDeviceAddressType::IOPort
         );
+
+        assert_eq!(test_struct.field_name(0x04), Some("description"));
+        assert_eq!(test_struct.field_name(0x0A), Some("address_type"));
     }
 }
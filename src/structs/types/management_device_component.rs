@@ -17,6 +17,12 @@ pub struct SMBiosManagementDeviceComponent<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosManagementDeviceComponent<'a> {
     const STRUCT_TYPE: u8 = 35u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "description"),
+        (0x05, "management_device_handle"),
+        (0x07, "component_handle"),
+        (0x09, "threshold_handle"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -100,5 +106,8 @@ mod tests {
         assert_eq!(*test_struct.management_device_handle().unwrap(), 38);
         assert_eq!(*test_struct.component_handle().unwrap(), 39);
         assert_eq!(*test_struct.threshold_handle().unwrap(), 40);
+
+        assert_eq!(test_struct.field_name(0x04), Some("description"));
+        assert_eq!(test_struct.field_name(0x09), Some("threshold_handle"));
     }
 }
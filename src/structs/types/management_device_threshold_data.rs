@@ -21,6 +21,14 @@ pub struct SMBiosManagementDeviceThresholdData<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosManagementDeviceThresholdData<'a> {
     const STRUCT_TYPE: u8 = 36u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "lower_threshold_non_critical"),
+        (0x06, "upper_threshold_non_critical"),
+        (0x08, "lower_threshold_critical"),
+        (0x0A, "upper_threshold_critical"),
+        (0x0C, "lower_threshold_non_recoverable"),
+        (0x0E, "upper_threshold_non_recoverable"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -138,5 +146,18 @@ mod tests {
         assert_eq!(test_struct.upper_threshold_critical(), Some(4));
         assert_eq!(test_struct.lower_threshold_non_recoverable(), Some(5));
         assert_eq!(test_struct.upper_threshold_non_recoverable(), Some(6));
+
+        assert_eq!(
+            test_struct.field_name(0x04),
+            Some("lower_threshold_non_critical")
+        );
+        assert_eq!(
+            test_struct.field_name(0x0A),
+            Some("upper_threshold_critical")
+        );
+        assert_eq!(
+            test_struct.field_name(0x0E),
+            Some("upper_threshold_non_recoverable")
+        );
     }
 }
@@ -18,6 +18,14 @@ pub struct SMBiosMemoryArrayMappedAddress<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosMemoryArrayMappedAddress<'a> {
     const STRUCT_TYPE: u8 = 19u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "starting_address"),
+        (0x08, "ending_address"),
+        (0x0C, "physical_memory_array_handle"),
+        (0x0E, "partition_width"),
+        (0x0F, "extended_starting_address"),
+        (0x17, "extended_ending_address"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -161,5 +169,12 @@ mod tests {
         assert_eq!(test_struct.partition_width(), Some(4));
         assert_eq!(test_struct.extended_starting_address(), Some(0));
         assert_eq!(test_struct.extended_ending_address(), Some(0));
+
+        assert_eq!(test_struct.field_name(0x04), Some("starting_address"));
+        assert_eq!(test_struct.field_name(0x0E), Some("partition_width"));
+        assert_eq!(
+            test_struct.field_name(0x17),
+            Some("extended_ending_address")
+        );
     }
 }
@@ -21,6 +21,11 @@ pub struct SMBiosMemoryChannel<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosMemoryChannel<'a> {
     const STRUCT_TYPE: u8 = 37u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "channel_type"),
+        (0x05, "maximum_channel_load"),
+        (0x06, "memory_device_count"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -346,5 +351,8 @@ mod tests {
         assert_eq!(second.load(), Some(3));
         assert_eq!(*second.handle().unwrap(), 4);
         assert!(iterator.next().is_none());
+
+        assert_eq!(test_struct.field_name(0x04), Some("channel_type"));
+        assert_eq!(test_struct.field_name(0x06), Some("memory_device_count"));
     }
 }
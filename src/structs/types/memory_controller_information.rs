@@ -18,6 +18,17 @@ pub struct SMBiosMemoryControllerInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosMemoryControllerInformation<'a> {
     const STRUCT_TYPE: u8 = 5u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "error_detecting_method"),
+        (0x05, "error_correcting_capability"),
+        (0x06, "supported_interleave"),
+        (0x07, "current_interleave"),
+        (0x08, "maximum_memory_module_size"),
+        (0x09, "supported_speeds"),
+        (0x0B, "supported_memory_types"),
+        (0x0D, "memory_module_voltage"),
+        (0x0E, "number_of_associated_memory_slots"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -942,5 +953,15 @@ mod tests {
         assert!(first.single_bit_error_correcting());
         let second = iterator.next().unwrap();
         assert!(second.double_bit_error_correcting());
+
+        assert_eq!(test_struct.field_name(0x04), Some("error_detecting_method"));
+        assert_eq!(
+            test_struct.field_name(0x08),
+            Some("maximum_memory_module_size")
+        );
+        assert_eq!(
+            test_struct.field_name(0x0E),
+            Some("number_of_associated_memory_slots")
+        );
     }
 }
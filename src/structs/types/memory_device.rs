@@ -17,6 +17,47 @@ pub struct SMBiosMemoryDevice<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosMemoryDevice<'a> {
     const STRUCT_TYPE: u8 = 17u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "physical_memory_array_handle"),
+        (0x06, "memory_error_information_handle"),
+        (0x08, "total_width"),
+        (0x0A, "data_width"),
+        (0x0C, "size"),
+        (0x0E, "form_factor"),
+        (0x0F, "device_set"),
+        (0x10, "device_locator"),
+        (0x11, "bank_locator"),
+        (0x12, "memory_type"),
+        (0x13, "type_detail"),
+        (0x15, "speed"),
+        (0x17, "manufacturer"),
+        (0x18, "serial_number"),
+        (0x19, "asset_tag"),
+        (0x1A, "part_number"),
+        (0x1B, "attributes"),
+        (0x1C, "extended_size"),
+        (0x20, "configured_memory_speed"),
+        (0x22, "minimum_voltage"),
+        (0x24, "maximum_voltage"),
+        (0x26, "configured_voltage"),
+        (0x28, "memory_technology"),
+        (0x29, "memory_operating_mode_capability"),
+        (0x2B, "firmware_version"),
+        (0x2C, "module_manufacturer_id"),
+        (0x2E, "module_product_id"),
+        (0x30, "memory_subsystem_controller_manufacturer_id"),
+        (0x32, "memory_subsystem_controller_product_id"),
+        (0x34, "non_volatile_size"),
+        (0x3C, "volatile_size"),
+        (0x44, "cache_size"),
+        (0x4C, "logical_size"),
+        (0x54, "extended_speed"),
+        (0x58, "extended_configured_memory_speed"),
+        (0x5C, "pmic0_manufacturer_id"),
+        (0x5E, "pmic0_revision_number"),
+        (0x60, "rcd_manufacturer_id"),
+        (0x62, "rcd_revision_number"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -371,6 +412,60 @@ impl<'a> SMBiosMemoryDevice<'a> {
     pub fn rcd_revision_number(&self) -> Option<u16> {
         self.parts.get_field_word(0x62)
     }
+
+    /// Size of the memory device in bytes
+    ///
+    /// Resolves the Size field at offset 0Ch, following it to the
+    /// Extended Size field when it is 7FFFh. Returns `None` when no
+    /// device is installed or the size is unknown.
+    pub fn effective_size(&self) -> Option<u64> {
+        const KIB: u64 = 1024;
+        const MIB: u64 = 1024 * 1024;
+        match self.size()? {
+            MemorySize::NotInstalled | MemorySize::Unknown => None,
+            MemorySize::Kilobytes(size) => Some(size as u64 * KIB),
+            MemorySize::Megabytes(size) => Some(size as u64 * MIB),
+            MemorySize::SeeExtendedSize => match self.extended_size()? {
+                MemorySizeExtended::Megabytes(size) => Some(size as u64 * MIB),
+                MemorySizeExtended::SeeSize => None,
+            },
+        }
+    }
+
+    /// Maximum capable speed of the memory device in megatransfers per second (MT/s)
+    ///
+    /// Resolves the Speed field at offset 15h, following it to the
+    /// Extended Speed field when it is FFFFh. Returns `None` when the
+    /// speed is unknown.
+    pub fn effective_speed(&self) -> Option<u32> {
+        resolve_memory_speed(self.speed(), self.extended_speed())
+    }
+
+    /// Configured speed of the memory device in megatransfers per second (MT/s)
+    ///
+    /// Resolves the Configured Memory Speed field at offset 20h, following
+    /// it to the Extended Configured Memory Speed field when it is FFFFh.
+    /// Returns `None` when the speed is unknown.
+    pub fn effective_configured_speed(&self) -> Option<u32> {
+        resolve_memory_speed(
+            self.configured_memory_speed(),
+            self.extended_configured_memory_speed(),
+        )
+    }
+}
+
+fn resolve_memory_speed(
+    speed: Option<MemorySpeed>,
+    extended_speed: Option<MemorySpeedExtended>,
+) -> Option<u32> {
+    match speed? {
+        MemorySpeed::MTs(speed) => Some(speed as u32),
+        MemorySpeed::SeeExtendedSpeed => match extended_speed? {
+            MemorySpeedExtended::MTs(speed) => Some(speed),
+            MemorySpeedExtended::SeeSpeed => None,
+        },
+        MemorySpeed::Unknown => None,
+    }
 }
 
 impl fmt::Debug for SMBiosMemoryDevice<'_> {
@@ -1276,6 +1371,14 @@ mod tests {
 
         let parts = UndefinedStruct::new(&struct_type17);
         let test_struct = SMBiosMemoryDevice::new(&parts);
+
+        assert_eq!(
+            test_struct.field_name(0x04),
+            Some("physical_memory_array_handle")
+        );
+        assert_eq!(test_struct.field_name(0x22), Some("minimum_voltage"));
+        assert_eq!(test_struct.field_name(0x62), Some("rcd_revision_number"));
+
         assert_eq!(test_struct.physical_memory_array_handle(), Some(Handle(62)));
         assert_eq!(
             test_struct.memory_error_information_handle(),
@@ -1406,4 +1509,50 @@ mod tests {
             Some(MemorySpeedExtended::MTs(0x05060708))
         );
     }
+
+    #[test]
+    fn test_effective_size_and_speed() {
+        let mut struct_type17 = vec![0u8; 0x5C];
+        struct_type17[0] = 0x11;
+        struct_type17[1] = 0x5C;
+        struct_type17.extend_from_slice(&[0x00, 0x00]);
+
+        // 8192 MB at 2666 MT/s, configured at 2400 MT/s
+        struct_type17[0x0C..0x0E].copy_from_slice(&0x2000u16.to_le_bytes());
+        struct_type17[0x15..0x17].copy_from_slice(&2666u16.to_le_bytes());
+        struct_type17[0x20..0x22].copy_from_slice(&2400u16.to_le_bytes());
+        let parts = UndefinedStruct::new(&struct_type17);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(test_struct.effective_size(), Some(8192 * 1024 * 1024));
+        assert_eq!(test_struct.effective_speed(), Some(2666));
+        assert_eq!(test_struct.effective_configured_speed(), Some(2400));
+
+        // 512 KB granularity
+        struct_type17[0x0C..0x0E].copy_from_slice(&0x8200u16.to_le_bytes());
+        let parts = UndefinedStruct::new(&struct_type17);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(test_struct.effective_size(), Some(512 * 1024));
+
+        // 64 GB and 70000 MT/s through the extended fields
+        struct_type17[0x0C..0x0E].copy_from_slice(&0x7FFFu16.to_le_bytes());
+        struct_type17[0x1C..0x20].copy_from_slice(&0x10000u32.to_le_bytes());
+        struct_type17[0x15..0x17].copy_from_slice(&0xFFFFu16.to_le_bytes());
+        struct_type17[0x54..0x58].copy_from_slice(&70000u32.to_le_bytes());
+        struct_type17[0x20..0x22].copy_from_slice(&0xFFFFu16.to_le_bytes());
+        struct_type17[0x58..0x5C].copy_from_slice(&0x8001_1170u32.to_le_bytes());
+        let parts = UndefinedStruct::new(&struct_type17);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(test_struct.effective_size(), Some(0x10000 * 1024 * 1024));
+        assert_eq!(test_struct.effective_speed(), Some(70000));
+        // Reserved bit 31 is ignored
+        assert_eq!(test_struct.effective_configured_speed(), Some(70000));
+
+        // Empty socket with unknown speed
+        struct_type17[0x0C..0x0E].copy_from_slice(&0u16.to_le_bytes());
+        struct_type17[0x15..0x17].copy_from_slice(&0u16.to_le_bytes());
+        let parts = UndefinedStruct::new(&struct_type17);
+        let test_struct = SMBiosMemoryDevice::new(&parts);
+        assert_eq!(test_struct.effective_size(), None);
+        assert_eq!(test_struct.effective_speed(), None);
+    }
 }
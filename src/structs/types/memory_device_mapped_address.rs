@@ -16,6 +16,17 @@ pub struct SMBiosMemoryDeviceMappedAddress<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosMemoryDeviceMappedAddress<'a> {
     const STRUCT_TYPE: u8 = 20u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "starting_address"),
+        (0x08, "ending_address"),
+        (0x0C, "memory_device_handle"),
+        (0x0E, "memory_array_mapped_address_handle"),
+        (0x10, "partition_row_position"),
+        (0x11, "interleave_position"),
+        (0x12, "interleaved_data_depth"),
+        (0x13, "extended_starting_address"),
+        (0x1B, "extended_ending_address"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -209,5 +220,12 @@ mod tests {
         assert_eq!(test_struct.interleaved_data_depth(), Some(2));
         assert_eq!(test_struct.extended_starting_address(), Some(0));
         assert_eq!(test_struct.extended_ending_address(), Some(0));
+
+        assert_eq!(test_struct.field_name(0x04), Some("starting_address"));
+        assert_eq!(test_struct.field_name(0x10), Some("partition_row_position"));
+        assert_eq!(
+            test_struct.field_name(0x1B),
+            Some("extended_ending_address")
+        );
     }
 }
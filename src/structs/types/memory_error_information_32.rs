@@ -16,6 +16,15 @@ pub struct SMBiosMemoryErrorInformation32<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosMemoryErrorInformation32<'a> {
     const STRUCT_TYPE: u8 = 18u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "error_type"),
+        (0x05, "error_granularity"),
+        (0x06, "error_operation"),
+        (0x07, "vendor_syndrome"),
+        (0x0B, "memory_array_error_address"),
+        (0x0F, "device_error_address"),
+        (0x13, "error_resolution"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -408,5 +417,9 @@ mod tests {
         assert_eq!(test_struct.memory_array_error_address(), Some(0x8000_0000));
         assert_eq!(test_struct.device_error_address(), Some(0x8000_0000));
         assert_eq!(test_struct.error_resolution(), Some(0x8000_0000));
+
+        assert_eq!(test_struct.field_name(0x04), Some("error_type"));
+        assert_eq!(test_struct.field_name(0x07), Some("vendor_syndrome"));
+        assert_eq!(test_struct.field_name(0x13), Some("error_resolution"));
     }
 }
@@ -18,6 +18,15 @@ pub struct SMBiosMemoryErrorInformation64<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosMemoryErrorInformation64<'a> {
     const STRUCT_TYPE: u8 = 33u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "error_type"),
+        (0x05, "error_granularity"),
+        (0x06, "error_operation"),
+        (0x07, "vendor_syndrome"),
+        (0x0B, "memory_array_error_address"),
+        (0x13, "device_error_address"),
+        (0x1B, "error_resolution"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -166,5 +175,9 @@ mod tests {
             Some(0x8000_0000_0000_0000)
         );
         assert_eq!(test_struct.error_resolution(), Some(0x8000_0000));
+
+        assert_eq!(test_struct.field_name(0x04), Some("error_type"));
+        assert_eq!(test_struct.field_name(0x07), Some("vendor_syndrome"));
+        assert_eq!(test_struct.field_name(0x1B), Some("error_resolution"));
     }
 }
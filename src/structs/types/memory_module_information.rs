@@ -16,6 +16,15 @@ pub struct SMBiosMemoryModuleInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosMemoryModuleInformation<'a> {
     const STRUCT_TYPE: u8 = 6u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "socket_designation"),
+        (0x05, "bank_connections"),
+        (0x06, "current_speed"),
+        (0x07, "current_memory_type"),
+        (0x09, "installed_size"),
+        (0x0A, "enabled_size"),
+        (0x0B, "error_status"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -143,5 +152,9 @@ mod tests {
         assert!(memory_types.simm());
         assert_eq!(test_struct.installed_size(), Some(0x7D));
         assert_eq!(test_struct.enabled_size(), Some(0x83));
+
+        assert_eq!(test_struct.field_name(0x04), Some("socket_designation"));
+        assert_eq!(test_struct.field_name(0x07), Some("current_memory_type"));
+        assert_eq!(test_struct.field_name(0x0B), Some("error_status"));
     }
 }
@@ -16,6 +16,7 @@ pub struct SMBiosOemStrings<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosOemStrings<'a> {
     const STRUCT_TYPE: u8 = 11u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[(0x04, "count")];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -97,5 +98,7 @@ mod tests {
             iter.next().unwrap().ok(),
             Some("BUILDID#13WWCDC8601#SABA#DABA;".to_string())
         );
+
+        assert_eq!(test_struct.field_name(0x04), Some("count"));
     }
 }
@@ -30,6 +30,17 @@ impl<'a> SMBiosStruct<'a> for SMBiosOnBoardDeviceInformation<'a> {
     fn parts(&self) -> &'a UndefinedStruct {
         self.parts
     }
+
+    fn field_name(&self, offset: usize) -> Option<&'static str> {
+        let entry_offset = offset.checked_sub(Header::SIZE)?;
+        if entry_offset >= self.number_of_devices() * OnBoardDevice::SIZE {
+            return None;
+        }
+        match entry_offset % OnBoardDevice::SIZE {
+            0 => Some("device_type"),
+            _ => Some("description"),
+        }
+    }
 }
 
 impl<'a> SMBiosOnBoardDeviceInformation<'a> {
@@ -371,5 +382,9 @@ mod tests {
         assert_eq!(device_type.status(), DeviceStatus::Enabled);
 
         assert!(iterator.next().is_none());
+
+        assert_eq!(test_struct.field_name(0x04), Some("device_type"));
+        assert_eq!(test_struct.field_name(0x05), Some("description"));
+        assert_eq!(test_struct.field_name(0x06), None);
     }
 }
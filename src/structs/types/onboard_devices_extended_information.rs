@@ -1,6 +1,6 @@
 use super::system_slot::{BusNumber, DeviceFunctionNumber, SegmentGroupNumber};
-use crate::core::{strings::*, UndefinedStruct};
-use crate::{OnBoardDeviceType, SMBiosStruct};
+use crate::core::{strings::*, SMBiosData, UndefinedStruct};
+use crate::{OnBoardDeviceType, SMBiosStruct, UefiDevicePath};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
 
@@ -28,6 +28,14 @@ pub struct SMBiosOnboardDevicesExtendedInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosOnboardDevicesExtendedInformation<'a> {
     const STRUCT_TYPE: u8 = 41u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "reference_designation"),
+        (0x05, "device_type"),
+        (0x06, "device_type_instance"),
+        (0x07, "segment_group_number"),
+        (0x09, "bus_number"),
+        (0x0A, "device_function_number"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -76,6 +84,11 @@ impl<'a> SMBiosOnboardDevicesExtendedInformation<'a> {
             .get_field_byte(0xA)
             .map(|raw| DeviceFunctionNumber::from(raw))
     }
+
+    /// UEFI device path of the device, given by a String Property (Type 46) of `data`
+    pub fn uefi_device_path(&self, data: &SMBiosData) -> Option<UefiDevicePath> {
+        data.uefi_device_path_for(self.parts.header.handle())
+    }
 }
 
 impl fmt::Debug for SMBiosOnboardDevicesExtendedInformation<'_> {
@@ -154,5 +167,9 @@ mod tests {
             }
             _ => panic!("expected device and function values"),
         }
+
+        assert_eq!(test_struct.field_name(0x04), Some("reference_designation"));
+        assert_eq!(test_struct.field_name(0x07), Some("segment_group_number"));
+        assert_eq!(test_struct.field_name(0x0A), Some("device_function_number"));
     }
 }
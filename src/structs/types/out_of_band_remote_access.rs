@@ -20,6 +20,8 @@ pub struct SMBiosOutOfBandRemoteAccess<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosOutOfBandRemoteAccess<'a> {
     const STRUCT_TYPE: u8 = 30u8;
+    const FIELDS: &'static [(usize, &'static str)] =
+        &[(0x04, "manufacturer_name"), (0x05, "connections")];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -167,5 +169,8 @@ mod tests {
         assert!(connections.inbound_connection_enabled());
         assert!(connections.outbound_connection_enabled());
         assert_eq!(connections.raw, 0x03);
+
+        assert_eq!(test_struct.field_name(0x04), Some("manufacturer_name"));
+        assert_eq!(test_struct.field_name(0x05), Some("connections"));
     }
 }
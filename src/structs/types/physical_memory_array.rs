@@ -15,6 +15,15 @@ pub struct SMBiosPhysicalMemoryArray<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosPhysicalMemoryArray<'a> {
     const STRUCT_TYPE: u8 = 16u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "location"),
+        (0x05, "usage"),
+        (0x06, "memory_error_correction"),
+        (0x07, "maximum_capacity"),
+        (0x0B, "memory_error_information_handle"),
+        (0x0D, "number_of_memory_devices"),
+        (0x0F, "extended_maximum_capacity"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -458,5 +467,12 @@ mod tests {
         );
         assert_eq!(test_struct.number_of_memory_devices(), Some(4));
         assert_eq!(test_struct.extended_maximum_capacity(), Some(0));
+
+        assert_eq!(test_struct.field_name(0x04), Some("location"));
+        assert_eq!(test_struct.field_name(0x07), Some("maximum_capacity"));
+        assert_eq!(
+            test_struct.field_name(0x0F),
+            Some("extended_maximum_capacity")
+        );
     }
 }
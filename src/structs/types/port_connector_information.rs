@@ -18,6 +18,13 @@ pub struct SMBiosPortConnectorInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosPortConnectorInformation<'a> {
     const STRUCT_TYPE: u8 = 8u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "internal_reference_designator"),
+        (0x05, "internal_connector_type"),
+        (0x06, "external_reference_designator"),
+        (0x07, "external_connector_type"),
+        (0x08, "port_type"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -523,5 +530,15 @@ mod tests {
             *test_struct.port_type().unwrap(),
             PortInformationPortType::MousePort
         );
+
+        assert_eq!(
+            test_struct.field_name(0x04),
+            Some("internal_reference_designator")
+        );
+        assert_eq!(
+            test_struct.field_name(0x06),
+            Some("external_reference_designator")
+        );
+        assert_eq!(test_struct.field_name(0x08), Some("port_type"));
     }
 }
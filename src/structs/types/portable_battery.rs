@@ -18,6 +18,23 @@ pub struct SMBiosPortableBattery<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosPortableBattery<'a> {
     const STRUCT_TYPE: u8 = 22u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "location"),
+        (0x05, "manufacturer"),
+        (0x06, "manufacture_date"),
+        (0x07, "serial_number"),
+        (0x08, "device_name"),
+        (0x09, "device_chemistry"),
+        (0x0A, "design_capacity"),
+        (0x0C, "design_voltage"),
+        (0x0E, "sbds_version_number"),
+        (0x0F, "maximum_error_in_battery_data"),
+        (0x10, "sbds_serial_number"),
+        (0x12, "sbds_manufacture_date"),
+        (0x14, "sbds_device_chemistry"),
+        (0x15, "design_capacity_multiplier"),
+        (0x16, "oem_specific"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -163,6 +180,81 @@ impl<'a> SMBiosPortableBattery<'a> {
     pub fn oem_specific(&self) -> Option<u32> {
         self.parts.get_field_dword(0x16)
     }
+
+    /// Date the cell pack was manufactured, decoded from
+    /// 'sbds_manufacture_date'
+    ///
+    /// `None` if the packed date is 0 or not a valid date.
+    pub fn decoded_sbds_manufacture_date(&self) -> Option<SbdsManufactureDate> {
+        self.sbds_manufacture_date()
+            .and_then(SbdsManufactureDate::from_packed)
+    }
+
+    /// The date on which the battery was manufactured
+    ///
+    /// Taken from 'manufacture_date' when it holds a string,
+    /// otherwise from 'sbds_manufacture_date'.
+    pub fn effective_manufacture_date(&self) -> Option<PortableBatteryManufactureDate> {
+        match non_empty(self.manufacture_date()) {
+            Some(date) => Some(PortableBatteryManufactureDate::String(date)),
+            None => self
+                .decoded_sbds_manufacture_date()
+                .map(PortableBatteryManufactureDate::Sbds),
+        }
+    }
+
+    /// The serial number for the battery
+    ///
+    /// Taken from 'serial_number' when it holds a string,
+    /// otherwise from 'sbds_serial_number'.
+    pub fn effective_serial_number(&self) -> Option<PortableBatterySerialNumber> {
+        match non_empty(self.serial_number()) {
+            Some(serial_number) => Some(PortableBatterySerialNumber::String(serial_number)),
+            None => self
+                .sbds_serial_number()
+                .map(PortableBatterySerialNumber::Sbds),
+        }
+    }
+
+    /// The battery chemistry
+    ///
+    /// Taken from 'sbds_device_chemistry' when 'device_chemistry'
+    /// is 02h (Unknown) and the SBDS string is present, otherwise
+    /// from 'device_chemistry'.
+    pub fn effective_device_chemistry(&self) -> Option<PortableBatteryChemistry> {
+        let device_chemistry = self.device_chemistry()?;
+        if device_chemistry.value == PortableBatteryDeviceChemistry::Unknown {
+            if let Some(chemistry) = non_empty(self.sbds_device_chemistry()) {
+                return Some(PortableBatteryChemistry::Sbds(chemistry));
+            }
+        }
+        Some(PortableBatteryChemistry::Standard(device_chemistry.value))
+    }
+
+    /// Design capacity of the battery in mWatt-hours, with
+    /// 'design_capacity_multiplier' applied
+    ///
+    /// `None` if the design capacity is unknown. The multiplier
+    /// defaults to 1 for structures which predate it.
+    pub fn effective_design_capacity(&self) -> Option<u32> {
+        match self.design_capacity()? {
+            PortableBatteryDesignCapacity::MilliWattHours(capacity) => {
+                let multiplier = match self.design_capacity_multiplier() {
+                    Some(0) | None => 1,
+                    Some(multiplier) => multiplier,
+                };
+                Some(capacity as u32 * multiplier as u32)
+            }
+            PortableBatteryDesignCapacity::Unknown => None,
+        }
+    }
+}
+
+fn non_empty(string: SMBiosString) -> Option<String> {
+    string
+        .to_utf8_lossy()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
 }
 
 impl fmt::Debug for SMBiosPortableBattery<'_> {
@@ -190,6 +282,19 @@ impl fmt::Debug for SMBiosPortableBattery<'_> {
                 &self.design_capacity_multiplier(),
             )
             .field("oem_specific", &self.oem_specific())
+            .field(
+                "effective_manufacture_date",
+                &self.effective_manufacture_date(),
+            )
+            .field("effective_serial_number", &self.effective_serial_number())
+            .field(
+                "effective_device_chemistry",
+                &self.effective_device_chemistry(),
+            )
+            .field(
+                "effective_design_capacity",
+                &self.effective_design_capacity(),
+            )
             .finish()
     }
 }
@@ -199,7 +304,7 @@ impl Serialize for SMBiosPortableBattery<'_> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("SMBiosPortableBattery", 16)?;
+        let mut state = serializer.serialize_struct("SMBiosPortableBattery", 20)?;
         state.serialize_field("header", &self.parts.header)?;
         state.serialize_field("location", &self.location())?;
         state.serialize_field("manufacturer", &self.manufacturer())?;
@@ -222,6 +327,19 @@ impl Serialize for SMBiosPortableBattery<'_> {
             &self.design_capacity_multiplier(),
         )?;
         state.serialize_field("oem_specific", &self.oem_specific())?;
+        state.serialize_field(
+            "effective_manufacture_date",
+            &self.effective_manufacture_date(),
+        )?;
+        state.serialize_field("effective_serial_number", &self.effective_serial_number())?;
+        state.serialize_field(
+            "effective_device_chemistry",
+            &self.effective_device_chemistry(),
+        )?;
+        state.serialize_field(
+            "effective_design_capacity",
+            &self.effective_design_capacity(),
+        )?;
         state.end()
     }
 }
@@ -354,6 +472,90 @@ impl From<u16> for PortableBatteryDesignVoltage {
     }
 }
 
+/// # Portable Battery - SBDS Manufacture Date
+///
+/// Date decoded from the packed SBDS format: bits 15:9 are the
+/// year biased by 1980, bits 8:5 the month and bits 4:0 the day.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SbdsManufactureDate {
+    /// Year, 1980 through 2107
+    pub year: u16,
+    /// Month, 1 through 12
+    pub month: u8,
+    /// Day of the month, 1 through 31
+    pub day: u8,
+}
+
+impl SbdsManufactureDate {
+    /// Decodes a packed SBDS date, `None` if the month or day is out of range
+    pub fn from_packed(packed: u16) -> Option<Self> {
+        let date = SbdsManufactureDate {
+            year: 1980 + (packed >> 9),
+            month: ((packed >> 5) & 0x0F) as u8,
+            day: (packed & 0x1F) as u8,
+        };
+        match (date.month, date.day) {
+            (1..=12, 1..=31) => Some(date),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SbdsManufactureDate {
+    /// Formatted as "YYYY-MM-DD"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// # Portable Battery - Manufacture Date
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub enum PortableBatteryManufactureDate {
+    /// Date given by the 'manufacture_date' string
+    String(String),
+    /// Date given by the 'sbds_manufacture_date' field
+    Sbds(SbdsManufactureDate),
+}
+
+impl fmt::Display for PortableBatteryManufactureDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PortableBatteryManufactureDate::String(date) => write!(f, "{}", date),
+            PortableBatteryManufactureDate::Sbds(date) => write!(f, "{}", date),
+        }
+    }
+}
+
+/// # Portable Battery - Serial Number
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub enum PortableBatterySerialNumber {
+    /// Serial number given by the 'serial_number' string
+    String(String),
+    /// Serial number given by the 'sbds_serial_number' field
+    Sbds(u16),
+}
+
+impl fmt::Display for PortableBatterySerialNumber {
+    /// The string, or the SBDS serial number in hex, e.g. "0x02C7"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PortableBatterySerialNumber::String(serial_number) => write!(f, "{}", serial_number),
+            PortableBatterySerialNumber::Sbds(serial_number) => {
+                write!(f, "{:#06X}", serial_number)
+            }
+        }
+    }
+}
+
+/// # Portable Battery - Chemistry
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub enum PortableBatteryChemistry {
+    /// Chemistry given by the 'device_chemistry' field
+    Standard(PortableBatteryDeviceChemistry),
+    /// Chemistry given by the 'sbds_device_chemistry' string, e.g. "LiP"
+    Sbds(String),
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -400,5 +602,61 @@ mod tests {
         );
         assert_eq!(test_struct.design_capacity_multiplier(), Some(10));
         assert_eq!(test_struct.oem_specific(), Some(0));
+
+        let sbds_date = test_struct.decoded_sbds_manufacture_date().unwrap();
+        assert_eq!(format!("{}", sbds_date), "2013-03-26");
+        assert_eq!(
+            test_struct.effective_manufacture_date(),
+            Some(PortableBatteryManufactureDate::Sbds(sbds_date))
+        );
+        assert_eq!(
+            test_struct.effective_serial_number(),
+            Some(PortableBatterySerialNumber::Sbds(711))
+        );
+        assert_eq!(
+            format!("{}", test_struct.effective_serial_number().unwrap()),
+            "0x02C7"
+        );
+        assert_eq!(
+            test_struct.effective_device_chemistry(),
+            Some(PortableBatteryChemistry::Sbds("LiP".to_string()))
+        );
+        assert_eq!(test_struct.effective_design_capacity(), Some(46030));
+
+        assert_eq!(test_struct.field_name(0x04), Some("location"));
+        assert_eq!(test_struct.field_name(0x0C), Some("design_voltage"));
+        assert_eq!(test_struct.field_name(0x16), Some("oem_specific"));
+    }
+
+    #[test]
+    fn effective_values_test() {
+        // Version 2.1 structure with a string serial number and date
+        let struct_type22 = vec![
+            0x16, 0x10, 0x2E, 0x00, 0x01, 0x00, 0x02, 0x03, 0x00, 0x06, 0xA0, 0x0F, 0x00, 0x00,
+            0x00, 0xFF, 0x46, 0x72, 0x6F, 0x6E, 0x74, 0x00, 0x30, 0x31, 0x2F, 0x30, 0x32, 0x2F,
+            0x32, 0x30, 0x32, 0x30, 0x00, 0x41, 0x42, 0x43, 0x31, 0x32, 0x33, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type22);
+        let test_struct = SMBiosPortableBattery::new(&parts);
+
+        assert_eq!(
+            test_struct.effective_manufacture_date(),
+            Some(PortableBatteryManufactureDate::String(
+                "01/02/2020".to_string()
+            ))
+        );
+        assert_eq!(
+            test_struct.effective_serial_number(),
+            Some(PortableBatterySerialNumber::String("ABC123".to_string()))
+        );
+        assert_eq!(
+            test_struct.effective_device_chemistry(),
+            Some(PortableBatteryChemistry::Standard(
+                PortableBatteryDeviceChemistry::LithiumIon
+            ))
+        );
+        assert_eq!(test_struct.effective_design_capacity(), Some(4000));
+        assert_eq!(SbdsManufactureDate::from_packed(0), None);
     }
 }
@@ -1,6 +1,7 @@
 use crate::core::{Handle, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::{field_name_at, SMBiosStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
+use std::convert::TryInto;
 use std::fmt;
 use std::ops::Deref;
 
@@ -25,6 +26,7 @@ pub struct SMBiosProcessorAdditionalInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosProcessorAdditionalInformation<'a> {
     const STRUCT_TYPE: u8 = 44u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[(0x04, "referenced_handle")];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -33,6 +35,13 @@ impl<'a> SMBiosStruct<'a> for SMBiosProcessorAdditionalInformation<'a> {
     fn parts(&self) -> &'a UndefinedStruct {
         self.parts
     }
+
+    fn field_name(&self, offset: usize) -> Option<&'static str> {
+        field_name_at(Self::FIELDS, offset).or_else(|| {
+            self.processor_specific_block()?
+                .field_name(offset.checked_sub(Self::PROCESSOR_SPECIFIC_BLOCK_OFFSET)?)
+        })
+    }
 }
 
 impl<'a> SMBiosProcessorAdditionalInformation<'a> {
@@ -112,6 +121,32 @@ impl<'a> ProcessorSpecificBlock<'a> {
             })
     }
 
+    /// Name of the accessor reading the field of this block at `offset`
+    /// within the block
+    fn field_name(&self, offset: usize) -> Option<&'static str> {
+        match offset {
+            Self::BLOCK_LENGTH_OFFSET => Some("block_length"),
+            Self::PROCESSOR_TYPE_OFFSET => Some("processor_type"),
+            _ => {
+                let data_offset = offset.checked_sub(Self::PROCESSOR_SPECIFIC_DATA_OFFSET)?;
+                let decoded_name = match self.decoded_data() {
+                    ProcessorSpecificData::RiscV(_) => {
+                        field_name_at(RiscVProcessorSpecificData::FIELDS, data_offset)
+                    }
+                    ProcessorSpecificData::LoongArch(_) => {
+                        field_name_at(LoongArchProcessorSpecificData::FIELDS, data_offset)
+                    }
+                    ProcessorSpecificData::Undecoded(_) => None,
+                };
+                match data_offset {
+                    0 => decoded_name.or(Some("processor_specific_data")),
+                    _ if data_offset < self.processor_specific_data().len() => decoded_name,
+                    _ => None,
+                }
+            }
+        }
+    }
+
     /// Length of 'processor_specific_data'
     pub fn block_length(&self) -> u8 {
         self.raw[Self::BLOCK_LENGTH_OFFSET]
@@ -127,6 +162,25 @@ impl<'a> ProcessorSpecificBlock<'a> {
     pub fn processor_specific_data(&self) -> &'a [u8] {
         &self.raw[Self::PROCESSOR_SPECIFIC_DATA_OFFSET..]
     }
+
+    /// 'processor_specific_data' decoded for the architecture named by 'processor_type'
+    ///
+    /// RISC-V and LoongArch blocks are decoded; blocks of other architectures
+    /// yield [ProcessorSpecificData::Undecoded].
+    pub fn decoded_data(&self) -> ProcessorSpecificData<'a> {
+        let data = self.processor_specific_data();
+        match self.processor_type().value {
+            ProcessorArchitectureType::RiscV32Bit
+            | ProcessorArchitectureType::RiscV64Bit
+            | ProcessorArchitectureType::RiscV128Bit => {
+                ProcessorSpecificData::RiscV(RiscVProcessorSpecificData { raw: data })
+            }
+            ProcessorArchitectureType::LoongArch32 | ProcessorArchitectureType::LoongArch64 => {
+                ProcessorSpecificData::LoongArch(LoongArchProcessorSpecificData { raw: data })
+            }
+            _ => ProcessorSpecificData::Undecoded(data),
+        }
+    }
 }
 
 impl fmt::Debug for ProcessorSpecificBlock<'_> {
@@ -135,6 +189,7 @@ impl fmt::Debug for ProcessorSpecificBlock<'_> {
             .field("block_length", &self.block_length())
             .field("processor_type", &self.processor_type())
             .field("processor_specific_data", &self.processor_specific_data())
+            .field("decoded_data", &self.decoded_data())
             .finish()
     }
 }
@@ -144,14 +199,520 @@ impl Serialize for ProcessorSpecificBlock<'_> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("ProcessorSpecificBlock", 3)?;
+        let mut state = serializer.serialize_struct("ProcessorSpecificBlock", 4)?;
         state.serialize_field("block_length", &self.block_length())?;
         state.serialize_field("processor_type", &self.processor_type())?;
         state.serialize_field("processor_specific_data", &self.processor_specific_data())?;
+        state.serialize_field("decoded_data", &self.decoded_data())?;
+        state.end()
+    }
+}
+
+/// # Processor-Specific Data
+///
+/// The 'processor_specific_data' of a [ProcessorSpecificBlock] decoded
+/// according to its processor architecture.
+#[derive(Serialize, Debug)]
+pub enum ProcessorSpecificData<'a> {
+    /// RISC-V processor-specific data (RV32, RV64 and RV128)
+    RiscV(RiscVProcessorSpecificData<'a>),
+    /// LoongArch processor-specific data (LoongArch32 and LoongArch64)
+    LoongArch(LoongArchProcessorSpecificData<'a>),
+    /// Data of an architecture whose block format is not decoded
+    Undecoded(&'a [u8]),
+}
+
+/// # RISC-V Processor-Specific Data
+///
+/// Processor-specific data of a RISC-V hart, as defined by the RISC-V
+/// processor-specific block structure of DSP0134. Fields beyond the end
+/// of the block return `None`.
+pub struct RiscVProcessorSpecificData<'a> {
+    /// Raw byte slice of the processor-specific data
+    pub raw: &'a [u8],
+}
+
+impl<'a> RiscVProcessorSpecificData<'a> {
+    /// Fields read by the accessors, as (offset within the data, accessor name)
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x00, "revision"),
+        (0x02, "structure_length"),
+        (0x03, "hart_id"),
+        (0x13, "boot_hart"),
+        (0x14, "machine_vendor_id"),
+        (0x24, "machine_architecture_id"),
+        (0x34, "machine_implementation_id"),
+        (0x44, "instruction_set_supported"),
+        (0x48, "privilege_level_supported"),
+        (0x49, "machine_exception_trap_delegation"),
+        (0x59, "machine_interrupt_trap_delegation"),
+        (0x69, "register_width"),
+        (0x6A, "machine_mode_xlen"),
+        (0x6C, "supervisor_mode_xlen"),
+        (0x6D, "user_mode_xlen"),
+    ];
+
+    fn get_byte(&self, offset: usize) -> Option<u8> {
+        self.raw.get(offset).copied()
+    }
+
+    fn get_word(&self, offset: usize) -> Option<u16> {
+        self.raw
+            .get(offset..offset + 2)
+            .map(|bytes| u16::from_le_bytes(bytes.try_into().expect("incorrect length")))
+    }
+
+    fn get_dword(&self, offset: usize) -> Option<u32> {
+        self.raw
+            .get(offset..offset + 4)
+            .map(|bytes| u32::from_le_bytes(bytes.try_into().expect("incorrect length")))
+    }
+
+    fn get_dqword(&self, offset: usize) -> Option<u128> {
+        self.raw
+            .get(offset..offset + 16)
+            .map(|bytes| u128::from_le_bytes(bytes.try_into().expect("incorrect length")))
+    }
+
+    /// Revision of the structure
+    ///
+    /// Bits 15:8 hold the major revision and bits 7:0 the minor
+    /// revision, e.g. 0100h for version 1.0.
+    pub fn revision(&self) -> Option<u16> {
+        self.get_word(0x00)
+    }
+
+    /// Length of the processor-specific data
+    pub fn structure_length(&self) -> Option<u8> {
+        self.get_byte(0x02)
+    }
+
+    /// ID of the hart
+    pub fn hart_id(&self) -> Option<u128> {
+        self.get_dqword(0x03)
+    }
+
+    /// `true` if the hart is the boot hart
+    pub fn boot_hart(&self) -> Option<bool> {
+        self.get_byte(0x13).map(|raw| raw == 1)
+    }
+
+    /// Machine Vendor ID (mvendorid CSR)
+    pub fn machine_vendor_id(&self) -> Option<u128> {
+        self.get_dqword(0x14)
+    }
+
+    /// Machine Architecture ID (marchid CSR)
+    pub fn machine_architecture_id(&self) -> Option<u128> {
+        self.get_dqword(0x24)
+    }
+
+    /// Machine Implementation ID (mimpid CSR)
+    pub fn machine_implementation_id(&self) -> Option<u128> {
+        self.get_dqword(0x34)
+    }
+
+    /// Instruction set extensions supported by the hart (misa CSR encoding)
+    pub fn instruction_set_supported(&self) -> Option<RiscVIsaExtensions> {
+        self.get_dword(0x44).map(RiscVIsaExtensions::from)
+    }
+
+    /// Privilege levels supported by the hart
+    pub fn privilege_level_supported(&self) -> Option<RiscVPrivilegeLevels> {
+        self.get_byte(0x48).map(RiscVPrivilegeLevels::from)
+    }
+
+    /// Machine exception trap delegation information (medeleg CSR)
+    pub fn machine_exception_trap_delegation(&self) -> Option<u128> {
+        self.get_dqword(0x49)
+    }
+
+    /// Machine interrupt trap delegation information (mideleg CSR)
+    pub fn machine_interrupt_trap_delegation(&self) -> Option<u128> {
+        self.get_dqword(0x59)
+    }
+
+    /// Register width (XLEN) of the hart
+    pub fn register_width(&self) -> Option<RiscVXlenData> {
+        self.get_byte(0x69).map(RiscVXlenData::from)
+    }
+
+    /// Register width (XLEN) in machine mode
+    pub fn machine_mode_xlen(&self) -> Option<RiscVXlenData> {
+        self.get_byte(0x6A).map(RiscVXlenData::from)
+    }
+
+    /// Register width (XLEN) in supervisor mode
+    pub fn supervisor_mode_xlen(&self) -> Option<RiscVXlenData> {
+        self.get_byte(0x6C).map(RiscVXlenData::from)
+    }
+
+    /// Register width (XLEN) in user mode
+    pub fn user_mode_xlen(&self) -> Option<RiscVXlenData> {
+        self.get_byte(0x6D).map(RiscVXlenData::from)
+    }
+}
+
+impl fmt::Debug for RiscVProcessorSpecificData<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<RiscVProcessorSpecificData<'_>>())
+            .field("revision", &self.revision())
+            .field("structure_length", &self.structure_length())
+            .field("hart_id", &self.hart_id())
+            .field("boot_hart", &self.boot_hart())
+            .field("machine_vendor_id", &self.machine_vendor_id())
+            .field("machine_architecture_id", &self.machine_architecture_id())
+            .field(
+                "machine_implementation_id",
+                &self.machine_implementation_id(),
+            )
+            .field(
+                "instruction_set_supported",
+                &self.instruction_set_supported(),
+            )
+            .field(
+                "privilege_level_supported",
+                &self.privilege_level_supported(),
+            )
+            .field(
+                "machine_exception_trap_delegation",
+                &self.machine_exception_trap_delegation(),
+            )
+            .field(
+                "machine_interrupt_trap_delegation",
+                &self.machine_interrupt_trap_delegation(),
+            )
+            .field("register_width", &self.register_width())
+            .field("machine_mode_xlen", &self.machine_mode_xlen())
+            .field("supervisor_mode_xlen", &self.supervisor_mode_xlen())
+            .field("user_mode_xlen", &self.user_mode_xlen())
+            .finish()
+    }
+}
+
+impl Serialize for RiscVProcessorSpecificData<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("RiscVProcessorSpecificData", 15)?;
+        state.serialize_field("revision", &self.revision())?;
+        state.serialize_field("structure_length", &self.structure_length())?;
+        state.serialize_field("hart_id", &self.hart_id())?;
+        state.serialize_field("boot_hart", &self.boot_hart())?;
+        state.serialize_field("machine_vendor_id", &self.machine_vendor_id())?;
+        state.serialize_field("machine_architecture_id", &self.machine_architecture_id())?;
+        state.serialize_field(
+            "machine_implementation_id",
+            &self.machine_implementation_id(),
+        )?;
+        state.serialize_field(
+            "instruction_set_supported",
+            &self.instruction_set_supported(),
+        )?;
+        state.serialize_field(
+            "privilege_level_supported",
+            &self.privilege_level_supported(),
+        )?;
+        state.serialize_field(
+            "machine_exception_trap_delegation",
+            &self.machine_exception_trap_delegation(),
+        )?;
+        state.serialize_field(
+            "machine_interrupt_trap_delegation",
+            &self.machine_interrupt_trap_delegation(),
+        )?;
+        state.serialize_field("register_width", &self.register_width())?;
+        state.serialize_field("machine_mode_xlen", &self.machine_mode_xlen())?;
+        state.serialize_field("supervisor_mode_xlen", &self.supervisor_mode_xlen())?;
+        state.serialize_field("user_mode_xlen", &self.user_mode_xlen())?;
+        state.end()
+    }
+}
+
+/// # LoongArch Processor-Specific Data
+///
+/// Processor-specific data of a LoongArch processor, as defined by the
+/// LoongArch processor-specific block structure of DSP0134. The block
+/// begins with its revision and length; the bytes which follow are
+/// returned by [LoongArchProcessorSpecificData::data]. Fields beyond the
+/// end of the block return `None`.
+pub struct LoongArchProcessorSpecificData<'a> {
+    /// Raw byte slice of the processor-specific data
+    pub raw: &'a [u8],
+}
+
+impl<'a> LoongArchProcessorSpecificData<'a> {
+    /// Fields read by the accessors, as (offset within the data, accessor name)
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x00, "revision"),
+        (0x02, "structure_length"),
+        (0x03, "data"),
+    ];
+
+    /// Revision of the structure
+    ///
+    /// Bits 15:8 hold the major revision and bits 7:0 the minor
+    /// revision, e.g. 0100h for version 1.0.
+    pub fn revision(&self) -> Option<u16> {
+        self.raw
+            .get(0x00..0x02)
+            .map(|bytes| u16::from_le_bytes(bytes.try_into().expect("incorrect length")))
+    }
+
+    /// Length of the processor-specific data
+    pub fn structure_length(&self) -> Option<u8> {
+        self.raw.get(0x02).copied()
+    }
+
+    /// Data following the structure length, up to the end of the structure
+    ///
+    /// The data ends at the end of the block when 'structure_length'
+    /// runs past it.
+    pub fn data(&self) -> Option<&'a [u8]> {
+        self.structure_length().map(|length| {
+            let end = (length as usize).clamp(0x03, self.raw.len());
+            &self.raw[0x03..end]
+        })
+    }
+}
+
+impl fmt::Debug for LoongArchProcessorSpecificData<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<LoongArchProcessorSpecificData<'_>>())
+            .field("revision", &self.revision())
+            .field("structure_length", &self.structure_length())
+            .field("data", &self.data())
+            .finish()
+    }
+}
+
+impl Serialize for LoongArchProcessorSpecificData<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("LoongArchProcessorSpecificData", 3)?;
+        state.serialize_field("revision", &self.revision())?;
+        state.serialize_field("structure_length", &self.structure_length())?;
+        state.serialize_field("data", &self.data())?;
         state.end()
     }
 }
 
+/// # RISC-V ISA Extensions
+///
+/// Bit _n_ set means the extension named by the _n_th letter of the
+/// alphabet is supported, e.g. bit 0 for "A" (atomics) and bit 8 for "I".
+#[derive(PartialEq, Eq)]
+pub struct RiscVIsaExtensions {
+    /// Raw value
+    pub raw: u32,
+}
+
+impl Deref for RiscVIsaExtensions {
+    type Target = u32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.raw
+    }
+}
+
+impl From<u32> for RiscVIsaExtensions {
+    fn from(raw: u32) -> Self {
+        RiscVIsaExtensions { raw }
+    }
+}
+
+impl RiscVIsaExtensions {
+    /// `true` if the extension with the given single letter name is supported
+    pub fn supports(&self, extension: char) -> bool {
+        match extension.to_ascii_uppercase() {
+            letter @ 'A'..='Z' => self.raw & (1 << (letter as u32 - 'A' as u32)) != 0,
+            _ => false,
+        }
+    }
+
+    /// Letters of the supported extensions in canonical order, e.g. "ACDFIMSU"
+    pub fn extensions(&self) -> String {
+        ('A'..='Z')
+            .filter(|&letter| self.supports(letter))
+            .collect()
+    }
+}
+
+impl fmt::Debug for RiscVIsaExtensions {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<RiscVIsaExtensions>())
+            .field("raw", &self.raw)
+            .field("extensions", &self.extensions())
+            .finish()
+    }
+}
+
+impl Serialize for RiscVIsaExtensions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("RiscVIsaExtensions", 2)?;
+        state.serialize_field("raw", &self.raw)?;
+        state.serialize_field("extensions", &self.extensions())?;
+        state.end()
+    }
+}
+
+/// # RISC-V Privilege Levels
+#[derive(PartialEq, Eq)]
+pub struct RiscVPrivilegeLevels {
+    /// Raw value
+    pub raw: u8,
+}
+
+impl Deref for RiscVPrivilegeLevels {
+    type Target = u8;
+
+    fn deref(&self) -> &Self::Target {
+        &self.raw
+    }
+}
+
+impl From<u8> for RiscVPrivilegeLevels {
+    fn from(raw: u8) -> Self {
+        RiscVPrivilegeLevels { raw }
+    }
+}
+
+impl RiscVPrivilegeLevels {
+    /// Bit 0 Machine mode
+    pub fn machine_mode(&self) -> bool {
+        self.raw & 0x01 == 0x01
+    }
+
+    /// Bit 2 Supervisor mode
+    pub fn supervisor_mode(&self) -> bool {
+        self.raw & 0x04 == 0x04
+    }
+
+    /// Bit 3 User mode
+    pub fn user_mode(&self) -> bool {
+        self.raw & 0x08 == 0x08
+    }
+
+    /// Bit 7 Debug mode
+    pub fn debug_mode(&self) -> bool {
+        self.raw & 0x80 == 0x80
+    }
+}
+
+impl fmt::Debug for RiscVPrivilegeLevels {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<RiscVPrivilegeLevels>())
+            .field("raw", &self.raw)
+            .field("machine_mode", &self.machine_mode())
+            .field("supervisor_mode", &self.supervisor_mode())
+            .field("user_mode", &self.user_mode())
+            .field("debug_mode", &self.debug_mode())
+            .finish()
+    }
+}
+
+impl Serialize for RiscVPrivilegeLevels {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("RiscVPrivilegeLevels", 5)?;
+        state.serialize_field("raw", &self.raw)?;
+        state.serialize_field("machine_mode", &self.machine_mode())?;
+        state.serialize_field("supervisor_mode", &self.supervisor_mode())?;
+        state.serialize_field("user_mode", &self.user_mode())?;
+        state.serialize_field("debug_mode", &self.debug_mode())?;
+        state.end()
+    }
+}
+
+/// # RISC-V Register Width Data
+pub struct RiscVXlenData {
+    /// Raw value
+    ///
+    /// _raw_ is most useful when _value_ is None.
+    /// This is most likely to occur when the standard was updated but
+    /// this library code has not been updated to match the current
+    /// standard.
+    pub raw: u8,
+    /// The contained [RiscVXlen] value
+    pub value: RiscVXlen,
+}
+
+impl fmt::Debug for RiscVXlenData {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<RiscVXlenData>())
+            .field("raw", &self.raw)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl Serialize for RiscVXlenData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("RiscVXlenData", 2)?;
+        state.serialize_field("raw", &self.raw)?;
+        state.serialize_field("value", &self.value)?;
+        state.end()
+    }
+}
+
+impl fmt::Display for RiscVXlenData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            RiscVXlen::None => write!(f, "{}", &self.raw),
+            _ => write!(f, "{:?}", &self.value),
+        }
+    }
+}
+
+impl Deref for RiscVXlenData {
+    type Target = RiscVXlen;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+/// # RISC-V Register Width
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub enum RiscVXlen {
+    /// The privilege mode is not supported
+    Unsupported,
+    /// 32-bit
+    Xlen32,
+    /// 64-bit
+    Xlen64,
+    /// 128-bit
+    Xlen128,
+    /// A value unknown to this standard, check the raw value
+    None,
+}
+
+impl From<u8> for RiscVXlenData {
+    fn from(raw: u8) -> Self {
+        RiscVXlenData {
+            value: match raw {
+                0x00 => RiscVXlen::Unsupported,
+                0x01 => RiscVXlen::Xlen32,
+                0x02 => RiscVXlen::Xlen64,
+                0x03 => RiscVXlen::Xlen128,
+                _ => RiscVXlen::None,
+            },
+            raw,
+        }
+    }
+}
+
 /// # Processor Architecture Types Data
 pub struct ProcessorArchitectureTypeData {
     /// Raw value
@@ -290,5 +851,120 @@ mod tests {
             processor_specific_block.processor_specific_data(),
             &[0x03, 0x02, 0x01]
         );
+
+        assert_eq!(test_struct.field_name(0x04), Some("referenced_handle"));
+        assert_eq!(test_struct.field_name(0x06), Some("block_length"));
+        assert_eq!(test_struct.field_name(0x07), Some("processor_type"));
+        assert_eq!(test_struct.field_name(0x08), Some("revision"));
+        assert_eq!(test_struct.field_name(0x0A), Some("structure_length"));
+        assert_eq!(test_struct.field_name(0x0B), None);
+    }
+
+    #[test]
+    fn riscv_test() {
+        let mut data = vec![0u8; 0x6E];
+        data[0x00..0x02].copy_from_slice(&0x0100u16.to_le_bytes()); // revision 1.0
+        data[0x02] = 0x6E; // structure length
+        data[0x03] = 0x02; // hart id
+        data[0x13] = 0x01; // boot hart
+        data[0x14..0x16].copy_from_slice(&0x0489u16.to_le_bytes()); // mvendorid
+        data[0x24] = 0x07; // marchid
+        data[0x34] = 0x01; // mimpid
+        data[0x44..0x48].copy_from_slice(&0x0014_112Du32.to_le_bytes()); // RV64IMAFDCSU
+        data[0x48] = 0x8D; // machine, supervisor, user and debug
+        data[0x49] = 0xFF; // medeleg
+        data[0x59] = 0x22; // mideleg
+        data[0x69] = 0x02;
+        data[0x6A] = 0x02;
+        data[0x6C] = 0x02;
+        data[0x6D] = 0x02;
+
+        let mut struct_type44 = vec![44u8, 6 + 2 + data.len() as u8, 0x2E, 0x00, 0x08, 0x00];
+        struct_type44.push(data.len() as u8);
+        struct_type44.push(0x07); // RiscV64Bit
+        struct_type44.extend_from_slice(&data);
+        struct_type44.extend_from_slice(&[0x00, 0x00]);
+
+        let parts = UndefinedStruct::new(&struct_type44);
+        let test_struct = SMBiosProcessorAdditionalInformation::new(&parts);
+        let riscv = match test_struct
+            .processor_specific_block()
+            .unwrap()
+            .decoded_data()
+        {
+            ProcessorSpecificData::RiscV(riscv) => riscv,
+            other => panic!("expected RISC-V data, got {:?}", other),
+        };
+
+        assert_eq!(riscv.revision(), Some(0x0100));
+        assert_eq!(riscv.structure_length(), Some(0x6E));
+        assert_eq!(riscv.hart_id(), Some(2));
+        assert_eq!(riscv.boot_hart(), Some(true));
+        assert_eq!(riscv.machine_vendor_id(), Some(0x489));
+        assert_eq!(riscv.machine_architecture_id(), Some(7));
+        assert_eq!(riscv.machine_implementation_id(), Some(1));
+        let extensions = riscv.instruction_set_supported().unwrap();
+        assert_eq!(extensions.extensions(), "ACDFIMSU");
+        assert!(extensions.supports('i'));
+        assert!(!extensions.supports('V'));
+        let privilege_levels = riscv.privilege_level_supported().unwrap();
+        assert!(privilege_levels.machine_mode());
+        assert!(privilege_levels.supervisor_mode());
+        assert!(privilege_levels.user_mode());
+        assert!(privilege_levels.debug_mode());
+        assert_eq!(riscv.machine_exception_trap_delegation(), Some(0xFF));
+        assert_eq!(riscv.machine_interrupt_trap_delegation(), Some(0x22));
+        assert_eq!(*riscv.register_width().unwrap(), RiscVXlen::Xlen64);
+        assert_eq!(*riscv.machine_mode_xlen().unwrap(), RiscVXlen::Xlen64);
+        assert_eq!(*riscv.supervisor_mode_xlen().unwrap(), RiscVXlen::Xlen64);
+        assert_eq!(*riscv.user_mode_xlen().unwrap(), RiscVXlen::Xlen64);
+
+        // Blocks of other architectures, here ARM64, are left undecoded
+        let struct_type44 = vec![
+            44u8, 0x0A, 0x2E, 0x00, 0x08, 0x00, 0x02, 0x05, 0x01, 0x02, 0x00, 0x00,
+        ];
+        let parts = UndefinedStruct::new(&struct_type44);
+        let test_struct = SMBiosProcessorAdditionalInformation::new(&parts);
+        match test_struct
+            .processor_specific_block()
+            .unwrap()
+            .decoded_data()
+        {
+            ProcessorSpecificData::Undecoded(data) => assert_eq!(data, &[0x01, 0x02]),
+            other => panic!("expected undecoded data, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn loongarch_test() {
+        let data = [0x00, 0x01, 0x07, 0x11, 0x22, 0x33, 0x44];
+        let mut struct_type44 = vec![44u8, 6 + 2 + data.len() as u8, 0x2E, 0x00, 0x08, 0x00];
+        struct_type44.push(data.len() as u8);
+        struct_type44.push(0x0A); // LoongArch64
+        struct_type44.extend_from_slice(&data);
+        struct_type44.extend_from_slice(&[0x00, 0x00]);
+
+        let parts = UndefinedStruct::new(&struct_type44);
+        let test_struct = SMBiosProcessorAdditionalInformation::new(&parts);
+        let block = test_struct.processor_specific_block().unwrap();
+        let loongarch = match block.decoded_data() {
+            ProcessorSpecificData::LoongArch(loongarch) => loongarch,
+            other => panic!("expected LoongArch data, got {:?}", other),
+        };
+
+        assert_eq!(loongarch.revision(), Some(0x0100));
+        assert_eq!(loongarch.structure_length(), Some(7));
+        assert_eq!(loongarch.data(), Some(&[0x11, 0x22, 0x33, 0x44][..]));
+        assert_eq!(test_struct.field_name(0x08), Some("revision"));
+        assert_eq!(test_struct.field_name(0x0A), Some("structure_length"));
+        assert_eq!(test_struct.field_name(0x0B), Some("data"));
+
+        // A length running past the block is cut at the end of the block
+        let truncated = LoongArchProcessorSpecificData {
+            raw: &[0x00, 0x01, 0x20, 0x11],
+        };
+        assert_eq!(truncated.data(), Some(&[0x11][..]));
+        let short = LoongArchProcessorSpecificData { raw: &[0x00, 0x01] };
+        assert_eq!(short.data(), None);
     }
 }
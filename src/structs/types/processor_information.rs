@@ -26,6 +26,36 @@ pub struct SMBiosProcessorInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosProcessorInformation<'a> {
     const STRUCT_TYPE: u8 = 4u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "socket_designation"),
+        (0x05, "processor_type"),
+        (0x06, "processor_family"),
+        (0x07, "processor_manufacturer"),
+        (0x08, "processor_id"),
+        (0x10, "processor_version"),
+        (0x11, "voltage"),
+        (0x12, "external_clock"),
+        (0x14, "max_speed"),
+        (0x16, "current_speed"),
+        (0x18, "status"),
+        (0x19, "processor_upgrade"),
+        (0x1A, "l1cache_handle"),
+        (0x1C, "l2cache_handle"),
+        (0x1E, "l3cache_handle"),
+        (0x20, "serial_number"),
+        (0x21, "asset_tag"),
+        (0x22, "part_number"),
+        (0x23, "core_count"),
+        (0x24, "cores_enabled"),
+        (0x25, "thread_count"),
+        (0x26, "processor_characteristics"),
+        (0x28, "processor_family_2"),
+        (0x2A, "core_count_2"),
+        (0x2C, "cores_enabled_2"),
+        (0x2E, "thread_count_2"),
+        (0x30, "thread_enabled"),
+        (0x32, "socket_type"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -64,17 +94,29 @@ impl<'a> SMBiosProcessorInformation<'a> {
     }
 
     /// Raw processor identification data
+    ///
+    /// See [SMBiosProcessorInformation::processor_id_data] for the decoded form.
     pub fn processor_id(&self) -> Option<&[u8; 8]> {
-        // Note: There are two more levels to the design of ProcessorId to consider.
-        // 1. These 8 bytes can represent one of 4 classes of processor (see 7.5.3)
-        //    perhaps return an enum with 4 variants.
-        // 2. Per variant the data represents specific Id information which can be
-        //    accomodated in specific representative structures.
         self.parts
             .get_field_data(0x08, 0x10)
             .map(|raw| raw.try_into().expect("incorrect length"))
     }
 
+    /// Processor identification data decoded for the class of processor
+    ///
+    /// The class (x86, ARM32, ARM64 or RISC-V, see 7.5.3) is chosen from
+    /// the processor family and, for ARM64, the 'arm_64soc_id'
+    /// processor characteristic.
+    pub fn processor_id_data(&self) -> Option<ProcessorIdData> {
+        let processor_id = self.processor_id()?;
+        let family = self.effective_processor_family().map(|family| family.raw);
+        let arm_64soc_id = matches!(
+            self.processor_characteristics(),
+            Some(characteristics) if characteristics.arm_64soc_id()
+        );
+        Some(ProcessorIdData::new(processor_id, family, arm_64soc_id))
+    }
+
     /// Processor version
     pub fn processor_version(&self) -> SMBiosString {
         self.parts.get_field_string(0x10)
@@ -308,6 +350,55 @@ impl<'a> SMBiosProcessorInformation<'a> {
     pub fn socket_type(&self) -> SMBiosString {
         self.parts.get_field_string(0x32)
     }
+
+    /// Processor family, resolved from 'processor_family' or, when it is
+    /// 'ProcessorFamily::SeeProcessorFamily2', from 'processor_family_2'
+    pub fn effective_processor_family(&self) -> Option<ProcessorFamilyData2> {
+        let family = self.processor_family()?;
+        match family.value {
+            ProcessorFamily::SeeProcessorFamily2 => self.processor_family_2(),
+            _ => Some(ProcessorFamilyData2::from(family.raw as u16)),
+        }
+    }
+
+    /// Number of cores per processor socket, resolved from 'core_count'
+    /// or, for 256 cores or more, from 'core_count_2'
+    pub fn effective_core_count(&self) -> Option<u16> {
+        match self.core_count()? {
+            CoreCount::Count(count) => Some(count as u16),
+            CoreCount::SeeCoreCount2 => match self.core_count_2()? {
+                CoreCount2::Count(count) => Some(count),
+                _ => None,
+            },
+            CoreCount::Unknown => None,
+        }
+    }
+
+    /// Number of enabled cores per processor socket, resolved from
+    /// 'cores_enabled' or, for 256 cores or more, from 'cores_enabled_2'
+    pub fn effective_cores_enabled(&self) -> Option<u16> {
+        match self.cores_enabled()? {
+            CoresEnabled::Count(count) => Some(count as u16),
+            CoresEnabled::SeeCoresEnabled2 => match self.cores_enabled_2()? {
+                CoresEnabled2::Count(count) => Some(count),
+                _ => None,
+            },
+            CoresEnabled::Unknown => None,
+        }
+    }
+
+    /// Number of threads per processor socket, resolved from
+    /// 'thread_count' or, for 256 threads or more, from 'thread_count_2'
+    pub fn effective_thread_count(&self) -> Option<u16> {
+        match self.thread_count()? {
+            ThreadCount::Count(count) => Some(count as u16),
+            ThreadCount::SeeThreadCount2 => match self.thread_count_2()? {
+                ThreadCount2::Count(count) => Some(count),
+                _ => None,
+            },
+            ThreadCount::Unknown => None,
+        }
+    }
 }
 
 impl fmt::Debug for SMBiosProcessorInformation<'_> {
@@ -319,6 +410,7 @@ impl fmt::Debug for SMBiosProcessorInformation<'_> {
             .field("processor_family", &self.processor_family())
             .field("processor_manufacturer", &self.processor_manufacturer())
             .field("processor_id", &self.processor_id())
+            .field("processor_id_data", &self.processor_id_data())
             .field("processor_version", &self.processor_version())
             .field("voltage", &self.voltage())
             .field("external_clock", &self.external_clock())
@@ -354,13 +446,14 @@ impl Serialize for SMBiosProcessorInformation<'_> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("SMBiosProcessorInformation", 28)?;
+        let mut state = serializer.serialize_struct("SMBiosProcessorInformation", 29)?;
         state.serialize_field("header", &self.parts.header)?;
         state.serialize_field("socket_designation", &self.socket_designation())?;
         state.serialize_field("processor_type", &self.processor_type())?;
         state.serialize_field("processor_family", &self.processor_family())?;
         state.serialize_field("processor_manufacturer", &self.processor_manufacturer())?;
         state.serialize_field("processor_id", &self.processor_id())?;
+        state.serialize_field("processor_id_data", &self.processor_id_data())?;
         state.serialize_field("processor_version", &self.processor_version())?;
         state.serialize_field("voltage", &self.voltage())?;
         state.serialize_field("external_clock", &self.external_clock())?;
@@ -2172,6 +2265,567 @@ impl From<u16> for ThreadEnabled {
     }
 }
 
+/// # Processor ID Data
+///
+/// The Processor ID field decoded according to the class of processor (7.5.3).
+#[derive(Serialize, Debug)]
+pub enum ProcessorIdData {
+    /// x86-class processor: the EAX and EDX values returned by CPUID leaf 1
+    X86(ProcessorIdX86),
+    /// ARM32-class processor: the Main ID Register (MIDR)
+    Arm32(ArmMidr),
+    /// ARM64-class processor: the Main ID Register (MIDR_EL1)
+    Arm64(ArmMidr),
+    /// ARM64-class processor with SoC ID support: the SoC ID version and revision
+    Arm64SocId(ArmSocId),
+    /// RISC-V-class processor: the Machine Vendor ID (mvendorid) CSR of hart 0
+    RiscV(u64),
+    /// Processor of another or unknown class
+    Unclassified([u8; 8]),
+}
+
+impl ProcessorIdData {
+    /// Decodes the Processor ID field for the given processor family
+    ///
+    /// `family` is the resolved family value (see
+    /// [SMBiosProcessorInformation::effective_processor_family]) and
+    /// `arm_64soc_id` the 'arm_64soc_id' processor characteristic.
+    pub fn new(processor_id: &[u8; 8], family: Option<u16>, arm_64soc_id: bool) -> Self {
+        let low = u32::from_le_bytes(processor_id[0..4].try_into().expect("incorrect length"));
+        let high = u32::from_le_bytes(processor_id[4..8].try_into().expect("incorrect length"));
+
+        match family {
+            Some(family) if is_x86_family(family) => ProcessorIdData::X86(ProcessorIdX86 {
+                signature: low,
+                feature_flags: X86FeatureFlags::from(high),
+            }),
+            // ARMv7, ARM, StrongARM
+            Some(0x100) | Some(0x118) | Some(0x119) => ProcessorIdData::Arm32(ArmMidr::from(low)),
+            // ARMv8, ARMv9
+            Some(0x101) | Some(0x102) if arm_64soc_id => ProcessorIdData::Arm64SocId(ArmSocId {
+                version: low,
+                revision: high,
+            }),
+            Some(0x101) | Some(0x102) => ProcessorIdData::Arm64(ArmMidr::from(low)),
+            // RV32, RV64, RV128
+            Some(0x200..=0x202) => ProcessorIdData::RiscV(u64::from_le_bytes(*processor_id)),
+            _ => ProcessorIdData::Unclassified(*processor_id),
+        }
+    }
+}
+
+/// Returns true for processor families implementing CPUID leaf 1
+fn is_x86_family(family: u16) -> bool {
+    matches!(family,
+        // Intel
+        0x0B..=0x16
+        | 0x28..=0x2F
+        | 0xA1..=0xB3
+        | 0xB5
+        | 0xB9..=0xC7
+        | 0xCD..=0xD0
+        | 0xD2..=0xDB
+        | 0xDD..=0xE0
+        | 0x300..=0x307
+        // AMD
+        | 0x18..=0x1D
+        | 0x1F
+        | 0x38..=0x3F
+        | 0x46..=0x4F
+        | 0x66..=0x68
+        | 0x6A..=0x6B
+        | 0x83..=0x8F
+        | 0xB6..=0xB7
+        | 0xE4..=0xEF
+        // Transmeta
+        | 0x78..=0x7A)
+}
+
+/// # x86 Processor ID
+///
+/// CPUID leaf 1 processor signature (EAX) and feature flags (EDX).
+#[derive(PartialEq, Eq)]
+pub struct ProcessorIdX86 {
+    /// Processor signature (EAX)
+    pub signature: u32,
+    /// Feature flags (EDX)
+    pub feature_flags: X86FeatureFlags,
+}
+
+impl ProcessorIdX86 {
+    /// Bits 3:0 Stepping ID
+    pub fn stepping(&self) -> u8 {
+        (self.signature & 0x0F) as u8
+    }
+
+    /// Bits 7:4 Model
+    pub fn model(&self) -> u8 {
+        ((self.signature >> 4) & 0x0F) as u8
+    }
+
+    /// Bits 11:8 Family ID
+    pub fn family(&self) -> u8 {
+        ((self.signature >> 8) & 0x0F) as u8
+    }
+
+    /// Bits 13:12 Processor Type
+    pub fn processor_type(&self) -> u8 {
+        ((self.signature >> 12) & 0x03) as u8
+    }
+
+    /// Bits 19:16 Extended Model ID
+    pub fn extended_model(&self) -> u8 {
+        ((self.signature >> 16) & 0x0F) as u8
+    }
+
+    /// Bits 27:20 Extended Family ID
+    pub fn extended_family(&self) -> u8 {
+        ((self.signature >> 20) & 0xFF) as u8
+    }
+
+    /// Family as displayed by software, including the Extended Family ID
+    /// when the Family ID is 0Fh
+    pub fn display_family(&self) -> u16 {
+        match self.family() {
+            0x0F => 0x0F + self.extended_family() as u16,
+            family => family as u16,
+        }
+    }
+
+    /// Model as displayed by software, including the Extended Model ID
+    /// when the Family ID is 06h or 0Fh
+    pub fn display_model(&self) -> u8 {
+        match self.family() {
+            0x06 | 0x0F => (self.extended_model() << 4) + self.model(),
+            _ => self.model(),
+        }
+    }
+}
+
+impl fmt::Debug for ProcessorIdX86 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<ProcessorIdX86>())
+            .field("signature", &self.signature)
+            .field("stepping", &self.stepping())
+            .field("model", &self.model())
+            .field("family", &self.family())
+            .field("processor_type", &self.processor_type())
+            .field("extended_model", &self.extended_model())
+            .field("extended_family", &self.extended_family())
+            .field("display_family", &self.display_family())
+            .field("display_model", &self.display_model())
+            .field("feature_flags", &self.feature_flags)
+            .finish()
+    }
+}
+
+impl Serialize for ProcessorIdX86 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ProcessorIdX86", 10)?;
+        state.serialize_field("signature", &self.signature)?;
+        state.serialize_field("stepping", &self.stepping())?;
+        state.serialize_field("model", &self.model())?;
+        state.serialize_field("family", &self.family())?;
+        state.serialize_field("processor_type", &self.processor_type())?;
+        state.serialize_field("extended_model", &self.extended_model())?;
+        state.serialize_field("extended_family", &self.extended_family())?;
+        state.serialize_field("display_family", &self.display_family())?;
+        state.serialize_field("display_model", &self.display_model())?;
+        state.serialize_field("feature_flags", &self.feature_flags)?;
+        state.end()
+    }
+}
+
+/// # x86 Feature Flags
+///
+/// CPUID leaf 1 feature flags (EDX).
+#[derive(PartialEq, Eq)]
+pub struct X86FeatureFlags {
+    /// Raw value
+    pub raw: u32,
+}
+
+impl Deref for X86FeatureFlags {
+    type Target = u32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.raw
+    }
+}
+
+impl From<u32> for X86FeatureFlags {
+    fn from(raw: u32) -> Self {
+        X86FeatureFlags { raw }
+    }
+}
+
+impl X86FeatureFlags {
+    /// Bit 0 Floating-Point Unit On-Chip
+    pub fn fpu(&self) -> bool {
+        self.raw & 0x00000001 == 0x00000001
+    }
+
+    /// Bit 1 Virtual Mode Extension
+    pub fn vme(&self) -> bool {
+        self.raw & 0x00000002 == 0x00000002
+    }
+
+    /// Bit 2 Debugging Extension
+    pub fn de(&self) -> bool {
+        self.raw & 0x00000004 == 0x00000004
+    }
+
+    /// Bit 3 Page Size Extension
+    pub fn pse(&self) -> bool {
+        self.raw & 0x00000008 == 0x00000008
+    }
+
+    /// Bit 4 Time Stamp Counter
+    pub fn tsc(&self) -> bool {
+        self.raw & 0x00000010 == 0x00000010
+    }
+
+    /// Bit 5 Model Specific Registers
+    pub fn msr(&self) -> bool {
+        self.raw & 0x00000020 == 0x00000020
+    }
+
+    /// Bit 6 Physical Address Extension
+    pub fn pae(&self) -> bool {
+        self.raw & 0x00000040 == 0x00000040
+    }
+
+    /// Bit 7 Machine Check Exception
+    pub fn mce(&self) -> bool {
+        self.raw & 0x00000080 == 0x00000080
+    }
+
+    /// Bit 8 CMPXCHG8B Instruction
+    pub fn cx8(&self) -> bool {
+        self.raw & 0x00000100 == 0x00000100
+    }
+
+    /// Bit 9 APIC On-Chip
+    pub fn apic(&self) -> bool {
+        self.raw & 0x00000200 == 0x00000200
+    }
+
+    /// Bit 11 SYSENTER and SYSEXIT Instructions
+    pub fn sep(&self) -> bool {
+        self.raw & 0x00000800 == 0x00000800
+    }
+
+    /// Bit 12 Memory Type Range Registers
+    pub fn mtrr(&self) -> bool {
+        self.raw & 0x00001000 == 0x00001000
+    }
+
+    /// Bit 13 Page Global Enable
+    pub fn pge(&self) -> bool {
+        self.raw & 0x00002000 == 0x00002000
+    }
+
+    /// Bit 14 Machine Check Architecture
+    pub fn mca(&self) -> bool {
+        self.raw & 0x00004000 == 0x00004000
+    }
+
+    /// Bit 15 Conditional Move Instructions
+    pub fn cmov(&self) -> bool {
+        self.raw & 0x00008000 == 0x00008000
+    }
+
+    /// Bit 16 Page Attribute Table
+    pub fn pat(&self) -> bool {
+        self.raw & 0x00010000 == 0x00010000
+    }
+
+    /// Bit 17 36-bit Page Size Extension
+    pub fn pse36(&self) -> bool {
+        self.raw & 0x00020000 == 0x00020000
+    }
+
+    /// Bit 18 Processor Serial Number
+    pub fn psn(&self) -> bool {
+        self.raw & 0x00040000 == 0x00040000
+    }
+
+    /// Bit 19 CLFLUSH Instruction
+    pub fn clfsh(&self) -> bool {
+        self.raw & 0x00080000 == 0x00080000
+    }
+
+    /// Bit 21 Debug Store
+    pub fn ds(&self) -> bool {
+        self.raw & 0x00200000 == 0x00200000
+    }
+
+    /// Bit 22 Thermal Monitor and Software Controlled Clock Facilities
+    pub fn acpi(&self) -> bool {
+        self.raw & 0x00400000 == 0x00400000
+    }
+
+    /// Bit 23 Intel MMX Technology
+    pub fn mmx(&self) -> bool {
+        self.raw & 0x00800000 == 0x00800000
+    }
+
+    /// Bit 24 FXSAVE and FXRSTOR Instructions
+    pub fn fxsr(&self) -> bool {
+        self.raw & 0x01000000 == 0x01000000
+    }
+
+    /// Bit 25 Streaming SIMD Extensions
+    pub fn sse(&self) -> bool {
+        self.raw & 0x02000000 == 0x02000000
+    }
+
+    /// Bit 26 Streaming SIMD Extensions 2
+    pub fn sse2(&self) -> bool {
+        self.raw & 0x04000000 == 0x04000000
+    }
+
+    /// Bit 27 Self Snoop
+    pub fn ss(&self) -> bool {
+        self.raw & 0x08000000 == 0x08000000
+    }
+
+    /// Bit 28 Max APIC IDs Reserved Field is Valid
+    pub fn htt(&self) -> bool {
+        self.raw & 0x10000000 == 0x10000000
+    }
+
+    /// Bit 29 Thermal Monitor
+    pub fn tm(&self) -> bool {
+        self.raw & 0x20000000 == 0x20000000
+    }
+
+    /// Bit 31 Pending Break Enable
+    pub fn pbe(&self) -> bool {
+        self.raw & 0x80000000 == 0x80000000
+    }
+}
+
+impl fmt::Debug for X86FeatureFlags {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<X86FeatureFlags>())
+            .field("raw", &self.raw)
+            .field("fpu", &self.fpu())
+            .field("vme", &self.vme())
+            .field("de", &self.de())
+            .field("pse", &self.pse())
+            .field("tsc", &self.tsc())
+            .field("msr", &self.msr())
+            .field("pae", &self.pae())
+            .field("mce", &self.mce())
+            .field("cx8", &self.cx8())
+            .field("apic", &self.apic())
+            .field("sep", &self.sep())
+            .field("mtrr", &self.mtrr())
+            .field("pge", &self.pge())
+            .field("mca", &self.mca())
+            .field("cmov", &self.cmov())
+            .field("pat", &self.pat())
+            .field("pse36", &self.pse36())
+            .field("psn", &self.psn())
+            .field("clfsh", &self.clfsh())
+            .field("ds", &self.ds())
+            .field("acpi", &self.acpi())
+            .field("mmx", &self.mmx())
+            .field("fxsr", &self.fxsr())
+            .field("sse", &self.sse())
+            .field("sse2", &self.sse2())
+            .field("ss", &self.ss())
+            .field("htt", &self.htt())
+            .field("tm", &self.tm())
+            .field("pbe", &self.pbe())
+            .finish()
+    }
+}
+
+impl Serialize for X86FeatureFlags {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("X86FeatureFlags", 30)?;
+        state.serialize_field("raw", &self.raw)?;
+        state.serialize_field("fpu", &self.fpu())?;
+        state.serialize_field("vme", &self.vme())?;
+        state.serialize_field("de", &self.de())?;
+        state.serialize_field("pse", &self.pse())?;
+        state.serialize_field("tsc", &self.tsc())?;
+        state.serialize_field("msr", &self.msr())?;
+        state.serialize_field("pae", &self.pae())?;
+        state.serialize_field("mce", &self.mce())?;
+        state.serialize_field("cx8", &self.cx8())?;
+        state.serialize_field("apic", &self.apic())?;
+        state.serialize_field("sep", &self.sep())?;
+        state.serialize_field("mtrr", &self.mtrr())?;
+        state.serialize_field("pge", &self.pge())?;
+        state.serialize_field("mca", &self.mca())?;
+        state.serialize_field("cmov", &self.cmov())?;
+        state.serialize_field("pat", &self.pat())?;
+        state.serialize_field("pse36", &self.pse36())?;
+        state.serialize_field("psn", &self.psn())?;
+        state.serialize_field("clfsh", &self.clfsh())?;
+        state.serialize_field("ds", &self.ds())?;
+        state.serialize_field("acpi", &self.acpi())?;
+        state.serialize_field("mmx", &self.mmx())?;
+        state.serialize_field("fxsr", &self.fxsr())?;
+        state.serialize_field("sse", &self.sse())?;
+        state.serialize_field("sse2", &self.sse2())?;
+        state.serialize_field("ss", &self.ss())?;
+        state.serialize_field("htt", &self.htt())?;
+        state.serialize_field("tm", &self.tm())?;
+        state.serialize_field("pbe", &self.pbe())?;
+        state.end()
+    }
+}
+
+/// # ARM Main ID Register
+///
+/// MIDR (ARM32) or MIDR_EL1 (ARM64).
+#[derive(PartialEq, Eq)]
+pub struct ArmMidr {
+    /// Raw value
+    pub raw: u32,
+}
+
+impl Deref for ArmMidr {
+    type Target = u32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.raw
+    }
+}
+
+impl From<u32> for ArmMidr {
+    fn from(raw: u32) -> Self {
+        ArmMidr { raw }
+    }
+}
+
+impl ArmMidr {
+    /// Bits 31:24 Implementer code, e.g. 41h for Arm Limited
+    pub fn implementer(&self) -> u8 {
+        (self.raw >> 24) as u8
+    }
+
+    /// Bits 23:20 Variant, the major revision
+    pub fn variant(&self) -> u8 {
+        ((self.raw >> 20) & 0x0F) as u8
+    }
+
+    /// Bits 19:16 Architecture
+    pub fn architecture(&self) -> u8 {
+        ((self.raw >> 16) & 0x0F) as u8
+    }
+
+    /// Bits 15:4 Primary part number
+    pub fn part_number(&self) -> u16 {
+        ((self.raw >> 4) & 0x0FFF) as u16
+    }
+
+    /// Bits 3:0 Revision, the minor revision
+    pub fn revision(&self) -> u8 {
+        (self.raw & 0x0F) as u8
+    }
+}
+
+impl fmt::Debug for ArmMidr {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<ArmMidr>())
+            .field("raw", &self.raw)
+            .field("implementer", &self.implementer())
+            .field("variant", &self.variant())
+            .field("architecture", &self.architecture())
+            .field("part_number", &self.part_number())
+            .field("revision", &self.revision())
+            .finish()
+    }
+}
+
+impl Serialize for ArmMidr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ArmMidr", 6)?;
+        state.serialize_field("raw", &self.raw)?;
+        state.serialize_field("implementer", &self.implementer())?;
+        state.serialize_field("variant", &self.variant())?;
+        state.serialize_field("architecture", &self.architecture())?;
+        state.serialize_field("part_number", &self.part_number())?;
+        state.serialize_field("revision", &self.revision())?;
+        state.end()
+    }
+}
+
+/// # ARM SoC ID
+///
+/// The SMCCC_ARCH_SOC_ID results of an ARM64-class processor.
+#[derive(PartialEq, Eq)]
+pub struct ArmSocId {
+    /// SoC ID version (SoC_ID_type 0)
+    pub version: u32,
+    /// SoC revision (SoC_ID_type 1)
+    pub revision: u32,
+}
+
+impl ArmSocId {
+    /// Bits 30:24 of the version, the JEP-106 continuation code (bank index)
+    pub fn jep106_bank_index(&self) -> u8 {
+        ((self.version >> 24) & 0x7F) as u8
+    }
+
+    /// Bits 23:16 of the version, the JEP-106 identification code
+    pub fn jep106_identification_code(&self) -> u8 {
+        ((self.version >> 16) & 0xFF) as u8
+    }
+
+    /// Bits 15:0 of the version, the implementation defined SoC ID
+    pub fn soc_id(&self) -> u16 {
+        (self.version & 0xFFFF) as u16
+    }
+}
+
+impl fmt::Debug for ArmSocId {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct(std::any::type_name::<ArmSocId>())
+            .field("version", &self.version)
+            .field("revision", &self.revision)
+            .field("jep106_bank_index", &self.jep106_bank_index())
+            .field(
+                "jep106_identification_code",
+                &self.jep106_identification_code(),
+            )
+            .field("soc_id", &self.soc_id())
+            .finish()
+    }
+}
+
+impl Serialize for ArmSocId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ArmSocId", 5)?;
+        state.serialize_field("version", &self.version)?;
+        state.serialize_field("revision", &self.revision)?;
+        state.serialize_field("jep106_bank_index", &self.jep106_bank_index())?;
+        state.serialize_field(
+            "jep106_identification_code",
+            &self.jep106_identification_code(),
+        )?;
+        state.serialize_field("soc_id", &self.soc_id())?;
+        state.end()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2285,5 +2939,95 @@ mod tests {
             ThreadCount2::Unknown => panic!("expected number"),
             ThreadCount2::Reserved => panic!("expected number"),
         }
+        assert_eq!(
+            *test_struct.effective_processor_family().unwrap(),
+            ProcessorFamily::IntelXeonProcessor
+        );
+        assert_eq!(test_struct.effective_core_count(), Some(6));
+        assert_eq!(test_struct.effective_cores_enabled(), Some(6));
+        assert_eq!(test_struct.effective_thread_count(), Some(12));
+
+        match test_struct.processor_id_data().unwrap() {
+            ProcessorIdData::X86(processor_id) => {
+                assert_eq!(processor_id.signature, 0x00050654);
+                assert_eq!(processor_id.stepping(), 4);
+                assert_eq!(processor_id.display_family(), 6);
+                assert_eq!(processor_id.display_model(), 0x55);
+                assert!(processor_id.feature_flags.fpu());
+                assert!(processor_id.feature_flags.sse2());
+                assert!(processor_id.feature_flags.htt());
+                assert!(!processor_id.feature_flags.psn());
+            }
+            _ => panic!("expected x86 processor id"),
+        }
+
+        assert_eq!(test_struct.field_name(0x04), Some("socket_designation"));
+        assert_eq!(test_struct.field_name(0x1E), Some("l3cache_handle"));
+        assert_eq!(test_struct.field_name(0x32), Some("socket_type"));
+    }
+
+    #[test]
+    fn processor_id_data_test() {
+        // Cortex-A72 r0p3
+        let processor_id = [0x83, 0xD0, 0x0F, 0x41, 0x00, 0x00, 0x00, 0x00];
+        match ProcessorIdData::new(&processor_id, Some(0x101), false) {
+            ProcessorIdData::Arm64(midr) => {
+                assert_eq!(midr.implementer(), 0x41);
+                assert_eq!(midr.variant(), 0);
+                assert_eq!(midr.architecture(), 0xF);
+                assert_eq!(midr.part_number(), 0xD08);
+                assert_eq!(midr.revision(), 3);
+            }
+            other => panic!("expected ARM64 MIDR, got {:?}", other),
+        }
+
+        let processor_id = [0x01, 0x00, 0x3B, 0x03, 0x02, 0x00, 0x00, 0x00];
+        match ProcessorIdData::new(&processor_id, Some(0x101), true) {
+            ProcessorIdData::Arm64SocId(soc_id) => {
+                assert_eq!(soc_id.jep106_bank_index(), 3);
+                assert_eq!(soc_id.jep106_identification_code(), 0x3B);
+                assert_eq!(soc_id.soc_id(), 1);
+                assert_eq!(soc_id.revision, 2);
+            }
+            other => panic!("expected ARM64 SoC ID, got {:?}", other),
+        }
+
+        let processor_id = [0x89, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+        match ProcessorIdData::new(&processor_id, Some(0x201), false) {
+            ProcessorIdData::RiscV(mvendorid) => assert_eq!(mvendorid, 0x589),
+            other => panic!("expected RISC-V, got {:?}", other),
+        }
+
+        match ProcessorIdData::new(&processor_id, Some(0x02), false) {
+            ProcessorIdData::Unclassified(raw) => assert_eq!(raw, processor_id),
+            other => panic!("expected unclassified, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn effective_values_test() {
+        // ARMv8 with 320 cores and 640 threads, given by the "2" fields
+        let mut struct_type4 = vec![0u8; 0x30];
+        struct_type4[0] = 0x04;
+        struct_type4[1] = 0x30;
+        struct_type4[0x06] = 0xFE;
+        struct_type4[0x23] = 0xFF;
+        struct_type4[0x24] = 0xFF;
+        struct_type4[0x25] = 0xFF;
+        struct_type4[0x28..0x2A].copy_from_slice(&0x101u16.to_le_bytes());
+        struct_type4[0x2A..0x2C].copy_from_slice(&320u16.to_le_bytes());
+        struct_type4[0x2C..0x2E].copy_from_slice(&318u16.to_le_bytes());
+        struct_type4[0x2E..0x30].copy_from_slice(&640u16.to_le_bytes());
+        struct_type4.extend_from_slice(&[0x00, 0x00]);
+
+        let parts = UndefinedStruct::new(&struct_type4);
+        let test_struct = SMBiosProcessorInformation::new(&parts);
+        assert_eq!(
+            *test_struct.effective_processor_family().unwrap(),
+            ProcessorFamily::ARMv8
+        );
+        assert_eq!(test_struct.effective_core_count(), Some(320));
+        assert_eq!(test_struct.effective_cores_enabled(), Some(318));
+        assert_eq!(test_struct.effective_thread_count(), Some(640));
     }
 }
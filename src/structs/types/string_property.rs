@@ -21,6 +21,11 @@ pub struct SMBiosStringProperty<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosStringProperty<'a> {
     const STRUCT_TYPE: u8 = 46u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "string_property_id"),
+        (0x06, "string_property_value"),
+        (0x07, "parent_handle"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -50,6 +55,19 @@ impl<'a> SMBiosStringProperty<'a> {
     pub fn parent_handle(&self) -> Option<Handle> {
         self.parts.get_field_handle(0x07)
     }
+
+    /// UEFI device path parsed from 'string_property_value'
+    ///
+    /// `None` unless the property is a [StringPropertyId::UefiDevicePath].
+    pub fn uefi_device_path(&self) -> Option<UefiDevicePath> {
+        match self.string_property_id()?.value {
+            StringPropertyId::UefiDevicePath => self
+                .string_property_value()
+                .to_utf8_lossy()
+                .map(|text| UefiDevicePath::parse(&text)),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Debug for SMBiosStringProperty<'_> {
@@ -59,6 +77,7 @@ impl fmt::Debug for SMBiosStringProperty<'_> {
             .field("string_property_id", &self.string_property_id())
             .field("string_property_value", &self.string_property_value())
             .field("parent_handle", &self.parent_handle())
+            .field("uefi_device_path", &self.uefi_device_path())
             .finish()
     }
 }
@@ -68,11 +87,12 @@ impl Serialize for SMBiosStringProperty<'_> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("SMBiosStringProperty", 3)?;
+        let mut state = serializer.serialize_struct("SMBiosStringProperty", 5)?;
         state.serialize_field("header", &self.parts.header)?;
         state.serialize_field("string_property_id", &self.string_property_id())?;
         state.serialize_field("string_property_value", &self.string_property_value())?;
         state.serialize_field("parent_handle", &self.parent_handle())?;
+        state.serialize_field("uefi_device_path", &self.uefi_device_path())?;
         state.end()
     }
 }
@@ -164,6 +184,106 @@ pub enum StringPropertyId {
     None,
 }
 
+/// # UEFI Device Path
+///
+/// Text form of a UEFI device path split into its nodes, e.g.
+/// "PciRoot(0x0)/Pci(0x1C,0x0)/Pci(0x0,0x0)".
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct UefiDevicePath {
+    /// Nodes of the path from the root
+    pub nodes: Vec<UefiDevicePathNode>,
+}
+
+impl UefiDevicePath {
+    /// Splits the text form of a device path into its nodes
+    ///
+    /// Nodes are separated by '/' outside of parentheses.
+    pub fn parse(text: &str) -> Self {
+        UefiDevicePath {
+            nodes: split_top_level(text.trim(), '/')
+                .into_iter()
+                .filter(|node| !node.is_empty())
+                .map(UefiDevicePathNode::parse)
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for UefiDevicePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, node) in self.nodes.iter().enumerate() {
+            if index > 0 {
+                write!(f, "/")?;
+            }
+            write!(f, "{}", node)?;
+        }
+        Ok(())
+    }
+}
+
+/// # UEFI Device Path Node
+///
+/// A node of a [UefiDevicePath], e.g. "Pci(0x1C,0x0)".
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct UefiDevicePathNode {
+    /// Node name, e.g. "Pci", or the whole node for nodes without arguments
+    pub name: String,
+    /// Arguments given in parentheses, e.g. ["0x1C", "0x0"]
+    ///
+    /// Omitted arguments are kept as empty strings so each argument
+    /// stays at its position, e.g. ["115200", "8", "", "1"] for
+    /// "Uart(115200,8,,1)".
+    pub arguments: Vec<String>,
+}
+
+impl UefiDevicePathNode {
+    fn parse(text: &str) -> Self {
+        match (text.find('('), text.ends_with(')')) {
+            (Some(open), true) => UefiDevicePathNode {
+                name: text[..open].to_string(),
+                arguments: split_top_level(&text[open + 1..text.len() - 1], ',')
+                    .into_iter()
+                    .map(|argument| argument.trim().to_string())
+                    .collect(),
+            },
+            _ => UefiDevicePathNode {
+                name: text.to_string(),
+                arguments: Vec::new(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for UefiDevicePathNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.arguments.is_empty() {
+            write!(f, "{}", self.name)
+        } else {
+            write!(f, "{}({})", self.name, self.arguments.join(","))
+        }
+    }
+}
+
+/// Splits text at each separator which is not enclosed in parentheses
+fn split_top_level(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ if c == separator && depth == 0 => {
+                parts.push(&text[start..index]);
+                start = index + c.len_utf8();
+            }
+            _ => (),
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -189,5 +309,45 @@ mod tests {
         assert_eq!(test_struct.string_property_value().to_string(), "Abcd");
 
         assert_eq!(*test_struct.parent_handle().unwrap(), 8u16);
+        assert_eq!(
+            test_struct.uefi_device_path(),
+            Some(UefiDevicePath {
+                nodes: vec![UefiDevicePathNode {
+                    name: "Abcd".to_string(),
+                    arguments: vec![],
+                }]
+            })
+        );
+
+        assert_eq!(test_struct.field_name(0x04), Some("string_property_id"));
+        assert_eq!(test_struct.field_name(0x07), Some("parent_handle"));
+    }
+
+    #[test]
+    fn uefi_device_path_test() {
+        let path = UefiDevicePath::parse(
+            "PciRoot(0x0)/Pci(0x1C,0x0)/Pci(0x0,0x0)/MAC(001122334455,0x1)/Uri(http://a/b)",
+        );
+
+        assert_eq!(path.nodes.len(), 5);
+        assert_eq!(path.nodes[0].name, "PciRoot");
+        assert_eq!(path.nodes[0].arguments, vec!["0x0"]);
+        assert_eq!(path.nodes[1].name, "Pci");
+        assert_eq!(path.nodes[1].arguments, vec!["0x1C", "0x0"]);
+        assert_eq!(path.nodes[4].arguments, vec!["http://a/b"]);
+        assert_eq!(
+            path.to_string(),
+            "PciRoot(0x0)/Pci(0x1C,0x0)/Pci(0x0,0x0)/MAC(001122334455,0x1)/Uri(http://a/b)"
+        );
+    }
+
+    #[test]
+    fn uefi_device_path_empty_arguments_test() {
+        let text = "PciRoot(0x0)/Pci(0x1F,0x0)/Uart(115200,8,,1)/Vendor(,)";
+        let path = UefiDevicePath::parse(text);
+
+        assert_eq!(path.nodes[2].arguments, vec!["115200", "8", "", "1"]);
+        assert_eq!(path.nodes[3].arguments, vec!["", ""]);
+        assert_eq!(path.to_string(), text);
     }
 }
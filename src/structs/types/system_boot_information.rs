@@ -18,6 +18,8 @@ pub struct SMBiosSystemBootInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosSystemBootInformation<'a> {
     const STRUCT_TYPE: u8 = 32u8;
+    const FIELDS: &'static [(usize, &'static str)] =
+        &[(Self::BOOT_STATUS_OFFSET, "boot_status_data")];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -99,15 +101,26 @@ impl<'a> SystemBootStatusData<'a> {
             0x06 => SystemBootStatus::SystemSecurityViolation,
             0x07 => SystemBootStatus::PreviouslyRequestedImage,
             0x08 => SystemBootStatus::SystemWatchdogTimerExpired,
+            0x80..=0xBF => SystemBootStatus::VendorOem(self.raw[0]),
+            0xC0..=0xFF => SystemBootStatus::ProductSpecific(self.raw[0]),
             _ => SystemBootStatus::None,
         }
     }
+
+    /// Additional data following the status byte
+    ///
+    /// Its format is defined by the status code, and is
+    /// empty when the field holds only the status.
+    pub fn additional_data(&self) -> &'a [u8] {
+        &self.raw[1..]
+    }
 }
 
 impl fmt::Debug for SystemBootStatusData<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt.debug_struct(std::any::type_name::<SMBiosSystemBootInformation<'_>>())
             .field("system_boot_status", &self.system_boot_status())
+            .field("additional_data", &self.additional_data())
             .finish()
     }
 }
@@ -117,8 +130,9 @@ impl Serialize for SystemBootStatusData<'_> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("SystemBootStatusData", 1)?;
+        let mut state = serializer.serialize_struct("SystemBootStatusData", 2)?;
         state.serialize_field("system_boot_status", &self.system_boot_status())?;
+        state.serialize_field("additional_data", &self.additional_data())?;
         state.end()
     }
 }
@@ -151,6 +165,17 @@ pub enum SystemBootStatus {
     PreviouslyRequestedImage,
     /// System watchdog timer expired, causing the system to reboot
     SystemWatchdogTimerExpired,
+    /// Vendor/OEM-specific implementations, 128 through 191
+    ///
+    /// The vendor/OEM identifier is the Manufacturer string
+    /// found in the System Information structure.
+    VendorOem(u8),
+    /// Product-specific implementations, 192 through 255
+    ///
+    /// The product identifier is formed by the concatenation
+    /// of the Manufacturer and Product Name strings found in
+    /// the System Information structure.
+    ProductSpecific(u8),
     /// A value unknown to this standard, check the raw value
     None,
 }
@@ -170,6 +195,8 @@ mod tests {
         let parts = UndefinedStruct::new(&struct_type32);
         let test_struct = SMBiosSystemBootInformation::new(&parts);
 
+        assert_eq!(test_struct.field_name(0x0A), Some("boot_status_data"));
+
         let boot_status_data = test_struct.boot_status_data().unwrap();
         assert_eq!(
             boot_status_data.raw,
@@ -206,4 +233,51 @@ mod tests {
 
         assert!(test_struct.boot_status_data().is_none());
     }
+
+    #[test]
+    fn vendor_and_product_specific_test() {
+        // OEM status 85h with additional data
+        let struct_type32 = vec![
+            0x20, 0x0D, 0x25, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x85, 0x12, 0x34, 0x00,
+            0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type32);
+        let test_struct = SMBiosSystemBootInformation::new(&parts);
+
+        let boot_status_data = test_struct.boot_status_data().unwrap();
+        assert_eq!(
+            boot_status_data.system_boot_status(),
+            SystemBootStatus::VendorOem(0x85)
+        );
+        assert_eq!(boot_status_data.additional_data(), &[0x12u8, 0x34] as &[u8]);
+
+        // Product-specific status C0h with no additional data
+        let struct_type32 = vec![
+            0x20, 0x0B, 0x25, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type32);
+        let test_struct = SMBiosSystemBootInformation::new(&parts);
+
+        let boot_status_data = test_struct.boot_status_data().unwrap();
+        assert_eq!(
+            boot_status_data.system_boot_status(),
+            SystemBootStatus::ProductSpecific(0xC0)
+        );
+        assert!(boot_status_data.additional_data().is_empty());
+
+        // Reserved status 09h
+        let struct_type32 = vec![
+            0x20, 0x0B, 0x25, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type32);
+        let test_struct = SMBiosSystemBootInformation::new(&parts);
+
+        assert_eq!(
+            test_struct.boot_status_data().unwrap().system_boot_status(),
+            SystemBootStatus::None
+        );
+    }
 }
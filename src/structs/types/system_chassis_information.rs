@@ -1,5 +1,5 @@
 use crate::core::{strings::*, UndefinedStruct};
-use crate::{BoardTypeData, SMBiosStruct, SMBiosType};
+use crate::{field_name_at, BoardTypeData, SMBiosStruct, SMBiosType};
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
 use std::ops::Deref;
@@ -21,6 +21,22 @@ pub struct SMBiosSystemChassisInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosSystemChassisInformation<'a> {
     const STRUCT_TYPE: u8 = 3u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "manufacturer"),
+        (0x05, "chassis_type"),
+        (0x06, "version"),
+        (0x07, "serial_number"),
+        (0x08, "asset_tag_number"),
+        (0x09, "bootup_state"),
+        (0x0A, "power_supply_state"),
+        (0x0B, "thermal_state"),
+        (0x0C, "security_status"),
+        (0x0D, "oem_defined"),
+        (0x11, "height"),
+        (0x12, "number_of_power_cords"),
+        (0x13, "contained_element_count"),
+        (0x14, "contained_element_record_length"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -29,6 +45,22 @@ impl<'a> SMBiosStruct<'a> for SMBiosSystemChassisInformation<'a> {
     fn parts(&self) -> &'a UndefinedStruct {
         self.parts
     }
+
+    fn field_name(&self, offset: usize) -> Option<&'static str> {
+        field_name_at(Self::FIELDS, offset).or_else(|| {
+            // SKU Number and the rack fields follow the contained elements
+            let size = self.contained_elements_size()?;
+            match offset.checked_sub(Self::CONTAINED_ELEMENTS_OFFSET)? {
+                0 if size > 0 => Some("contained_elements"),
+                position => match position.checked_sub(size)? {
+                    0 => Some("sku_number"),
+                    1 => Some("rack_type"),
+                    2 => Some("rack_height"),
+                    _ => None,
+                },
+            }
+        })
+    }
 }
 
 impl<'a> SMBiosSystemChassisInformation<'a> {
@@ -1081,5 +1113,12 @@ mod tests {
             test_struct.sku_number().to_string(),
             "Default string".to_string()
         );
+
+        // SKU Number follows the two 3 byte contained elements
+        assert_eq!(test_struct.field_name(0x04), Some("manufacturer"));
+        assert_eq!(test_struct.field_name(0x12), Some("number_of_power_cords"));
+        assert_eq!(test_struct.field_name(0x15), Some("contained_elements"));
+        assert_eq!(test_struct.field_name(0x16), None);
+        assert_eq!(test_struct.field_name(0x1B), Some("sku_number"));
     }
 }
@@ -15,6 +15,7 @@ pub struct SMBiosSystemConfigurationOptions<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosSystemConfigurationOptions<'a> {
     const STRUCT_TYPE: u8 = 12u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[(0x04, "count")];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -39,6 +40,17 @@ impl<'a> SMBiosSystemConfigurationOptions<'a> {
     pub fn configuration_strings(&self) -> &SMBiosStringSet {
         &self.parts.strings
     }
+
+    /// Configuration options with their designator and setting
+    /// parsed where the string follows the "designator: setting"
+    /// form, e.g. "JP1: 1-2 Closed"
+    pub fn configuration_options(&self) -> Vec<SystemConfigurationOption> {
+        self.parts
+            .strings
+            .iter_numbered()
+            .map(|(index, raw)| SystemConfigurationOption::new(index, raw))
+            .collect()
+    }
 }
 
 impl fmt::Debug for SMBiosSystemConfigurationOptions<'_> {
@@ -47,6 +59,7 @@ impl fmt::Debug for SMBiosSystemConfigurationOptions<'_> {
             .field("header", &self.parts.header)
             .field("count", &self.count())
             .field("configuration_strings", &self.configuration_strings())
+            .field("configuration_options", &self.configuration_options())
             .finish()
     }
 }
@@ -56,14 +69,63 @@ impl Serialize for SMBiosSystemConfigurationOptions<'_> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("SMBiosSystemConfigurationOptions", 3)?;
+        let mut state = serializer.serialize_struct("SMBiosSystemConfigurationOptions", 4)?;
         state.serialize_field("header", &self.parts.header)?;
         state.serialize_field("count", &self.count())?;
         state.serialize_field("configuration_strings", &self.configuration_strings())?;
+        state.serialize_field("configuration_options", &self.configuration_options())?;
         state.end()
     }
 }
 
+/// # System Configuration Option
+///
+/// A jumper or switch setting of [SMBiosSystemConfigurationOptions].
+#[derive(Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct SystemConfigurationOption {
+    /// String number of the option, starting at 1
+    pub index: u8,
+    /// The option string
+    pub text: String,
+    /// Jumper or switch designator, e.g. "JP1" or "SW1-1"
+    ///
+    /// `None` if the string does not begin with a designator
+    /// followed by ':'.
+    pub designator: Option<String>,
+    /// Setting following the designator, e.g. "1-2 Closed"
+    pub setting: Option<String>,
+}
+
+impl SystemConfigurationOption {
+    fn new(index: u8, raw: &[u8]) -> Self {
+        let text = String::from_utf8_lossy(raw).trim().to_string();
+        let (designator, setting) = match text.split_once(':') {
+            Some((designator, setting))
+                if !designator.is_empty() && !designator.contains(char::is_whitespace) =>
+            {
+                (
+                    Some(designator.to_string()),
+                    Some(setting.trim().to_string()),
+                )
+            }
+            _ => (None, None),
+        };
+
+        SystemConfigurationOption {
+            index,
+            text,
+            designator,
+            setting,
+        }
+    }
+}
+
+impl fmt::Display for SystemConfigurationOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -87,5 +149,49 @@ mod tests {
                 .ok(),
             Some("scre++".to_string())
         );
+        assert_eq!(
+            test_struct.configuration_options(),
+            vec![SystemConfigurationOption {
+                index: 1,
+                text: "scre++".to_string(),
+                designator: None,
+                setting: None,
+            }]
+        );
+
+        assert_eq!(test_struct.field_name(0x04), Some("count"));
+    }
+
+    #[test]
+    fn configuration_options_test() {
+        let mut struct_type12 = vec![0x0C, 0x05, 0x23, 0x00, 0x03];
+        for string in [
+            &b"JP1: 1-2 Closed"[..],
+            b"SW1-1: Close to Disable On Board Video",
+            b"Clear CMOS: short pins",
+        ]
+        .iter()
+        {
+            struct_type12.extend_from_slice(string);
+            struct_type12.push(0x00);
+        }
+        struct_type12.push(0x00);
+
+        let parts = UndefinedStruct::new(&struct_type12);
+        let test_struct = SMBiosSystemConfigurationOptions::new(&parts);
+        let options = test_struct.configuration_options();
+
+        assert_eq!(options.len(), 3);
+        assert_eq!(options[0].index, 1);
+        assert_eq!(options[0].designator.as_deref(), Some("JP1"));
+        assert_eq!(options[0].setting.as_deref(), Some("1-2 Closed"));
+        assert_eq!(options[1].designator.as_deref(), Some("SW1-1"));
+        assert_eq!(
+            options[1].setting.as_deref(),
+            Some("Close to Disable On Board Video")
+        );
+        assert_eq!(options[2].index, 3);
+        assert_eq!(options[2].designator, None);
+        assert_eq!(format!("{}", options[2]), "Clear CMOS: short pins");
     }
 }
@@ -19,6 +19,18 @@ pub struct SMBiosSystemEventLog<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosSystemEventLog<'a> {
     const STRUCT_TYPE: u8 = 15u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "log_area_length"),
+        (0x06, "log_header_start_offset"),
+        (0x08, "log_data_start_offset"),
+        (0x0A, "access_method"),
+        (0x0B, "log_status"),
+        (0x0C, "log_change_token"),
+        (0x10, "access_method_address"),
+        (0x14, "log_header_format"),
+        (0x15, "number_of_supported_log_type_descriptors"),
+        (0x16, "length_of_each_log_type_descriptor"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -96,6 +108,14 @@ impl<'a> SMBiosSystemEventLog<'a> {
         self.parts.get_field_dword(0x10)
     }
 
+    /// Address associated with the access method, decoded
+    /// per the Access Method field value
+    pub fn decoded_access_method_address(&self) -> Option<AccessMethodAddress> {
+        let access_method = self.access_method()?;
+        let address = self.access_method_address()?;
+        Some(AccessMethodAddress::new(&access_method.value, address))
+    }
+
     /// Format of the log header area
     pub fn log_header_format(&self) -> Option<HeaderFormatData> {
         self.parts
@@ -141,6 +161,10 @@ impl fmt::Debug for SMBiosSystemEventLog<'_> {
             .field("log_status", &self.log_status())
             .field("log_change_token", &self.log_change_token())
             .field("access_method_address", &self.access_method_address())
+            .field(
+                "decoded_access_method_address",
+                &self.decoded_access_method_address(),
+            )
             .field("log_header_format", &self.log_header_format())
             .field(
                 "number_of_supported_log_type_descriptors",
@@ -160,7 +184,7 @@ impl Serialize for SMBiosSystemEventLog<'_> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("SMBiosSystemEventLog", 12)?;
+        let mut state = serializer.serialize_struct("SMBiosSystemEventLog", 13)?;
         state.serialize_field("header", &self.parts.header)?;
         state.serialize_field("log_area_length", &self.log_area_length())?;
         state.serialize_field("log_header_start_offset", &self.log_header_start_offset())?;
@@ -169,6 +193,10 @@ impl Serialize for SMBiosSystemEventLog<'_> {
         state.serialize_field("log_status", &self.log_status())?;
         state.serialize_field("log_change_token", &self.log_change_token())?;
         state.serialize_field("access_method_address", &self.access_method_address())?;
+        state.serialize_field(
+            "decoded_access_method_address",
+            &self.decoded_access_method_address(),
+        )?;
         state.serialize_field("log_header_format", &self.log_header_format())?;
         state.serialize_field(
             "number_of_supported_log_type_descriptors",
@@ -506,6 +534,48 @@ impl From<u8> for AccessMethod {
     }
 }
 
+/// # System Event Log - Access Method Address
+///
+/// The Access Method Address field decoded per [AccessMethod].
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AccessMethodAddress {
+    /// I/O addresses of the index and data ports of the indexed I/O access methods
+    ///
+    /// For [AccessMethod::IndexedIO28Bit] the index is written in two bytes, the
+    /// low byte to _index_port_ and the high byte to _index_port_ + 1.
+    IndexedIO {
+        /// I/O address of the index port
+        index_port: u16,
+        /// I/O address of the data port
+        data_port: u16,
+    },
+    /// Starting physical address of a memory-mapped log
+    PhysicalAddress(u32),
+    /// GPNV handle of a log available through General-Purpose NonVolatile Data functions
+    GpnvHandle(u16),
+    /// Address of an access method unknown to this standard
+    Unknown(u32),
+}
+
+impl AccessMethodAddress {
+    /// Decodes the Access Method Address field for the given access method
+    pub fn new(access_method: &AccessMethod, address: u32) -> Self {
+        match access_method {
+            AccessMethod::IndexedIO18Bit
+            | AccessMethod::IndexedIO28Bit
+            | AccessMethod::IndexedIO116Bit => AccessMethodAddress::IndexedIO {
+                index_port: address as u16,
+                data_port: (address >> 16) as u16,
+            },
+            AccessMethod::MemoryMapped32Bit => AccessMethodAddress::PhysicalAddress(address),
+            AccessMethod::GeneralPurposeNonVolatile => {
+                AccessMethodAddress::GpnvHandle(address as u16)
+            }
+            AccessMethod::None => AccessMethodAddress::Unknown(address),
+        }
+    }
+}
+
 /// System Event Log Type Descriptor
 ///
 /// Each entry consists of a 1-byte type field and a 1-byte data-format descriptor, as shown in Table 61. The
@@ -856,6 +926,10 @@ mod tests {
         );
         assert_eq!(test_struct.log_change_token(), Some(5));
         assert_eq!(test_struct.access_method_address(), Some(1789796376));
+        assert_eq!(
+            test_struct.decoded_access_method_address(),
+            Some(AccessMethodAddress::PhysicalAddress(1789796376))
+        );
         assert_eq!(
             *test_struct.log_header_format().unwrap(),
             HeaderFormat::Type1LogHeader
@@ -870,5 +944,31 @@ mod tests {
         let mut iterator = type_descriptors.into_iter();
         let first = iterator.next().unwrap();
         assert_eq!(*first.log_type(), LogType::SingleBitEccMemoryError);
+
+        assert_eq!(test_struct.field_name(0x04), Some("log_area_length"));
+        assert_eq!(test_struct.field_name(0x0C), Some("log_change_token"));
+        assert_eq!(
+            test_struct.field_name(0x16),
+            Some("length_of_each_log_type_descriptor")
+        );
+    }
+
+    #[test]
+    fn access_method_address_test() {
+        assert_eq!(
+            AccessMethodAddress::new(&AccessMethod::IndexedIO28Bit, 0x0C81_0C80),
+            AccessMethodAddress::IndexedIO {
+                index_port: 0x0C80,
+                data_port: 0x0C81
+            }
+        );
+        assert_eq!(
+            AccessMethodAddress::new(&AccessMethod::GeneralPurposeNonVolatile, 0x0000_0012),
+            AccessMethodAddress::GpnvHandle(0x12)
+        );
+        assert_eq!(
+            AccessMethodAddress::new(&AccessMethod::None, 0x1234),
+            AccessMethodAddress::Unknown(0x1234)
+        );
     }
 }
@@ -23,6 +23,16 @@ pub struct SMBiosSystemInformation<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosSystemInformation<'a> {
     const STRUCT_TYPE: u8 = 1u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "manufacturer"),
+        (0x05, "product_name"),
+        (0x06, "version"),
+        (0x07, "serial_number"),
+        (0x08, "uuid"),
+        (0x18, "wakeup_type"),
+        (0x19, "sku_number"),
+        (0x1A, "family"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -408,5 +418,9 @@ mod tests {
             test_struct.family().to_string(),
             "ThinkStation P520".to_string()
         );
+
+        assert_eq!(test_struct.field_name(0x04), Some("manufacturer"));
+        assert_eq!(test_struct.field_name(0x08), Some("uuid"));
+        assert_eq!(test_struct.field_name(0x1A), Some("family"));
     }
 }
@@ -1,4 +1,4 @@
-use crate::{SMBiosStruct, UndefinedStruct};
+use crate::{BcdField, SMBiosStruct, UndefinedStruct};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use std::fmt;
 
@@ -21,6 +21,13 @@ pub struct SMBiosSystemPowerControls<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosSystemPowerControls<'a> {
     const STRUCT_TYPE: u8 = 25u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "next_scheduled_power_on_month"),
+        (0x05, "next_scheduled_power_on_day_of_month"),
+        (0x06, "next_scheduled_power_on_hour"),
+        (0x07, "next_scheduled_power_on_minute"),
+        (0x08, "next_scheduled_power_on_second"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -71,6 +78,32 @@ impl<'a> SMBiosSystemPowerControls<'a> {
     pub fn next_scheduled_power_on_second(&self) -> Option<u8> {
         self.parts.get_field_byte(0x08)
     }
+
+    /// Next scheduled power-on
+    ///
+    /// The month, day-of-month, hour, minute and second fields
+    /// decoded from BCD.
+    ///
+    /// `None` when all five fields are zero, which firmware writes when
+    /// no power-on is scheduled.
+    pub fn scheduled_power_on(&self) -> Option<ScheduledPowerOn> {
+        let month = self.next_scheduled_power_on_month()?;
+        let day_of_month = self.next_scheduled_power_on_day_of_month()?;
+        let hour = self.next_scheduled_power_on_hour()?;
+        let minute = self.next_scheduled_power_on_minute()?;
+        let second = self.next_scheduled_power_on_second()?;
+        if [month, day_of_month, hour, minute, second] == [0; 5] {
+            return None;
+        }
+
+        Some(ScheduledPowerOn {
+            month: BcdField::new(month, 1..=12),
+            day_of_month: BcdField::new(day_of_month, 1..=31),
+            hour: BcdField::new(hour, 0..=23),
+            minute: BcdField::new(minute, 0..=59),
+            second: BcdField::new(second, 0..=59),
+        })
+    }
 }
 
 impl fmt::Debug for SMBiosSystemPowerControls<'_> {
@@ -97,6 +130,7 @@ impl fmt::Debug for SMBiosSystemPowerControls<'_> {
                 "next_scheduled_power_on_second",
                 &self.next_scheduled_power_on_second(),
             )
+            .field("scheduled_power_on", &self.scheduled_power_on())
             .finish()
     }
 }
@@ -106,7 +140,7 @@ impl Serialize for SMBiosSystemPowerControls<'_> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("SMBiosSystemPowerControls", 6)?;
+        let mut state = serializer.serialize_struct("SMBiosSystemPowerControls", 7)?;
         state.serialize_field("header", &self.parts.header)?;
         state.serialize_field(
             "next_scheduled_power_on_month",
@@ -128,10 +162,55 @@ impl Serialize for SMBiosSystemPowerControls<'_> {
             "next_scheduled_power_on_second",
             &self.next_scheduled_power_on_second(),
         )?;
+        state.serialize_field("scheduled_power_on", &self.scheduled_power_on())?;
         state.end()
     }
 }
 
+/// # Scheduled Power-On
+///
+/// Date and time of the next scheduled power-on. A [BcdField::Wildcard]
+/// field is not used to determine the next power-on.
+#[derive(Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ScheduledPowerOn {
+    /// Month, 1 through 12
+    pub month: BcdField,
+    /// Day of the month, 1 through 31
+    pub day_of_month: BcdField,
+    /// Hour, 0 through 23
+    pub hour: BcdField,
+    /// Minute, 0 through 59
+    pub minute: BcdField,
+    /// Second, 0 through 59
+    pub second: BcdField,
+}
+
+impl ScheduledPowerOn {
+    /// `true` if no field holds an invalid BCD value
+    pub fn is_valid(&self) -> bool {
+        [
+            self.month,
+            self.day_of_month,
+            self.hour,
+            self.minute,
+            self.second,
+        ]
+        .iter()
+        .all(|field| !matches!(field, BcdField::Invalid(_)))
+    }
+}
+
+impl fmt::Display for ScheduledPowerOn {
+    /// Formatted as "month-day hour:minute:second", e.g. "*-15 06:30:00"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{} {}:{}:{}",
+            self.month, self.day_of_month, self.hour, self.minute, self.second
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -150,5 +229,68 @@ mod tests {
         assert_eq!(test_struct.next_scheduled_power_on_hour(), Some(0));
         assert_eq!(test_struct.next_scheduled_power_on_minute(), Some(0));
         assert_eq!(test_struct.next_scheduled_power_on_second(), Some(0));
+        assert_eq!(test_struct.scheduled_power_on(), None);
+
+        assert_eq!(
+            test_struct.field_name(0x04),
+            Some("next_scheduled_power_on_month")
+        );
+        assert_eq!(
+            test_struct.field_name(0x06),
+            Some("next_scheduled_power_on_hour")
+        );
+        assert_eq!(
+            test_struct.field_name(0x08),
+            Some("next_scheduled_power_on_second")
+        );
+    }
+
+    #[test]
+    fn scheduled_power_on_test() {
+        let struct_type25 = vec![
+            0x19, 0x09, 0x27, 0x00, 0xFF, 0x15, 0x06, 0x30, 0x00, 0x00, 0x00,
+        ];
+
+        let parts = UndefinedStruct::new(&struct_type25);
+        let test_struct = SMBiosSystemPowerControls::new(&parts);
+
+        let power_on = test_struct.scheduled_power_on().unwrap();
+        assert_eq!(power_on.month, BcdField::Wildcard);
+        assert_eq!(power_on.day_of_month, BcdField::Value(15));
+        assert_eq!(power_on.hour, BcdField::Value(6));
+        assert!(power_on.is_valid());
+        assert_eq!(format!("{}", power_on), "*-15 06:30:00");
+
+        // A zero month is invalid once any other field is set
+        let struct_type25 = vec![
+            0x19, 0x09, 0x27, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let parts = UndefinedStruct::new(&struct_type25);
+        let power_on = SMBiosSystemPowerControls::new(&parts)
+            .scheduled_power_on()
+            .unwrap();
+        assert!(!power_on.is_valid());
+        assert_eq!(power_on.month, BcdField::Invalid(0));
+    }
+
+    #[test]
+    fn scheduled_power_on_not_scheduled_test() {
+        // Firmware without a scheduled power-on writes all zeroes
+        let struct_type25 = vec![
+            0x19, 0x09, 0x27, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let parts = UndefinedStruct::new(&struct_type25);
+        assert_eq!(
+            SMBiosSystemPowerControls::new(&parts).scheduled_power_on(),
+            None
+        );
+
+        // Fields missing from a short structure also give no schedule
+        let struct_type25 = vec![0x19, 0x06, 0x27, 0x00, 0x01, 0x15, 0x00, 0x00];
+        let parts = UndefinedStruct::new(&struct_type25);
+        assert_eq!(
+            SMBiosSystemPowerControls::new(&parts).scheduled_power_on(),
+            None
+        );
     }
 }
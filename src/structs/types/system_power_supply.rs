@@ -19,6 +19,21 @@ pub struct SMBiosSystemPowerSupply<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosSystemPowerSupply<'a> {
     const STRUCT_TYPE: u8 = 39u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "power_unit_group"),
+        (0x05, "location"),
+        (0x06, "device_name"),
+        (0x07, "manufacturer"),
+        (0x08, "serial_number"),
+        (0x09, "asset_tag_number"),
+        (0x0A, "model_part_number"),
+        (0x0B, "revision_level"),
+        (0x0C, "max_power_capacity"),
+        (0x0E, "power_supply_characteristics"),
+        (0x10, "input_voltage_probe_handle"),
+        (0x12, "cooling_device_handle"),
+        (0x14, "input_current_probe_handle"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -493,5 +508,12 @@ mod tests {
         assert_eq!(*test_struct.input_voltage_probe_handle().unwrap(), 54);
         assert_eq!(*test_struct.cooling_device_handle().unwrap(), 56);
         assert_eq!(*test_struct.input_current_probe_handle().unwrap(), 57);
+
+        assert_eq!(test_struct.field_name(0x04), Some("power_unit_group"));
+        assert_eq!(test_struct.field_name(0x0A), Some("model_part_number"));
+        assert_eq!(
+            test_struct.field_name(0x14),
+            Some("input_current_probe_handle")
+        );
     }
 }
@@ -21,6 +21,13 @@ pub struct SMBiosSystemReset<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosSystemReset<'a> {
     const STRUCT_TYPE: u8 = 23u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "capabilities"),
+        (0x05, "reset_count"),
+        (0x07, "reset_limit"),
+        (0x09, "timer_interval"),
+        (0x0B, "timeout"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -354,5 +361,9 @@ mod tests {
             Timeout::Minutes(_) => panic!("expected unknown"),
             Timeout::Unknown => (),
         }
+
+        assert_eq!(test_struct.field_name(0x04), Some("capabilities"));
+        assert_eq!(test_struct.field_name(0x07), Some("reset_limit"));
+        assert_eq!(test_struct.field_name(0x0B), Some("timeout"));
     }
 }
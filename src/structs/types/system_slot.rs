@@ -1,5 +1,5 @@
-use crate::core::{strings::*, UndefinedStruct};
-use crate::SMBiosStruct;
+use crate::core::{strings::*, SMBiosData, UndefinedStruct};
+use crate::{field_name_at, SMBiosStruct, UefiDevicePath};
 use serde::{ser::SerializeSeq, ser::SerializeStruct, Serialize, Serializer};
 use std::{convert::TryInto, fmt, ops::Deref};
 
@@ -17,6 +17,21 @@ pub struct SMBiosSystemSlot<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosSystemSlot<'a> {
     const STRUCT_TYPE: u8 = 9u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "slot_designation"),
+        (0x05, "system_slot_type"),
+        (0x06, "slot_data_bus_width"),
+        (0x07, "current_usage"),
+        (0x08, "slot_length"),
+        (0x09, "slot_id"),
+        (0x0B, "slot_characteristics_1"),
+        (0x0C, "slot_characteristics_2"),
+        (0x0D, "segment_group_number"),
+        (0x0F, "bus_number"),
+        (0x10, "device_function_number"),
+        (0x11, "data_bus_width"),
+        (0x12, "peer_group_count"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -25,6 +40,23 @@ impl<'a> SMBiosStruct<'a> for SMBiosSystemSlot<'a> {
     fn parts(&self) -> &'a UndefinedStruct {
         self.parts
     }
+
+    fn field_name(&self, offset: usize) -> Option<&'static str> {
+        field_name_at(Self::FIELDS, offset).or_else(|| {
+            // The fields from Slot Information on follow the peer groups
+            let size = self.peer_group_size()?;
+            match offset.checked_sub(SlotPeerGroupIterator::PEER_GROUPS_OFFSET)? {
+                0 if size > 0 => Some("peer_group_iterator"),
+                position => match position.checked_sub(size)? {
+                    0 => Some("slot_information"),
+                    1 => Some("slot_physical_width"),
+                    2 => Some("slot_pitch"),
+                    4 => Some("slot_height"),
+                    _ => None,
+                },
+            }
+        })
+    }
 }
 
 impl<'a> SMBiosSystemSlot<'a> {
@@ -176,6 +208,11 @@ impl<'a> SMBiosSystemSlot<'a> {
                 .map(|raw| SlotHeightData::from(raw))
         })
     }
+
+    /// UEFI device path of the slot, given by a String Property (Type 46) of `data`
+    pub fn uefi_device_path(&self, data: &SMBiosData) -> Option<UefiDevicePath> {
+        data.uefi_device_path_for(self.parts.header.handle())
+    }
 }
 
 impl fmt::Debug for SMBiosSystemSlot<'_> {
@@ -1450,6 +1487,15 @@ mod tests {
         assert_eq!(*test_struct.slot_physical_width().unwrap(), SlotWidth::X1);
         assert_eq!(test_struct.slot_pitch(), Some(0x09AB));
 
+        // One peer group moves the fields from Slot Information on by 5
+        assert_eq!(test_struct.field_name(0x04), Some("slot_designation"));
+        assert_eq!(test_struct.field_name(0x12), Some("peer_group_count"));
+        assert_eq!(test_struct.field_name(0x13), Some("peer_group_iterator"));
+        assert_eq!(test_struct.field_name(0x18), Some("slot_information"));
+        assert_eq!(test_struct.field_name(0x1A), Some("slot_pitch"));
+        assert_eq!(test_struct.field_name(0x1B), None);
+        assert_eq!(test_struct.field_name(0x1C), Some("slot_height"));
+
         println!("{:?}", test_struct);
     }
 }
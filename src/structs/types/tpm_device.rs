@@ -10,6 +10,16 @@ pub struct SMBiosTpmDevice<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosTpmDevice<'a> {
     const STRUCT_TYPE: u8 = 43u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "vendor_id"),
+        (0x08, "major_spec_version"),
+        (0x09, "minor_spec_version"),
+        (0x0A, "firmware_version_1"),
+        (0x0E, "firmware_version_2"),
+        (0x12, "description"),
+        (0x13, "characteristics"),
+        (0x1B, "oem_defined"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -323,5 +333,9 @@ mod tests {
             Some(TpmDeviceCharacteristics::from(16))
         );
         assert_eq!(test_struct.oem_defined(), Some(0));
+
+        assert_eq!(test_struct.field_name(0x04), Some("vendor_id"));
+        assert_eq!(test_struct.field_name(0x0E), Some("firmware_version_2"));
+        assert_eq!(test_struct.field_name(0x1B), Some("oem_defined"));
     }
 }
@@ -15,6 +15,17 @@ pub struct SMBiosVoltageProbe<'a> {
 
 impl<'a> SMBiosStruct<'a> for SMBiosVoltageProbe<'a> {
     const STRUCT_TYPE: u8 = 26u8;
+    const FIELDS: &'static [(usize, &'static str)] = &[
+        (0x04, "description"),
+        (0x05, "location_and_status"),
+        (0x06, "maximum_value"),
+        (0x08, "minimum_value"),
+        (0x0A, "resolution"),
+        (0x0C, "tolerance"),
+        (0x0E, "accuracy"),
+        (0x10, "oem_defined"),
+        (0x14, "nominal_value"),
+    ];
 
     fn new(parts: &'a UndefinedStruct) -> Self {
         Self { parts }
@@ -376,5 +387,9 @@ mod tests {
             ProbeVoltage::Millivolts(_) => panic!("expected unknown"),
             ProbeVoltage::Unknown => (),
         }
+
+        assert_eq!(test_struct.field_name(0x04), Some("description"));
+        assert_eq!(test_struct.field_name(0x0A), Some("resolution"));
+        assert_eq!(test_struct.field_name(0x14), Some("nominal_value"));
     }
 }
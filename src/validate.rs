@@ -0,0 +1,486 @@
+//! Checks an SMBIOS table for conformance with the DMTF SMBIOS specification.
+//!
+//! [validate] runs a fixed rule set and returns a [ValidationReport] of
+//! [Finding]s, each graded by [Severity]. Firmware release gates typically
+//! fail when [ValidationReport::max_severity] reaches a chosen threshold.
+use crate::core::{Handle, SMBiosData, SMBiosStringError, SMBiosVersion, UndefinedStruct};
+use crate::structs::*;
+use serde::Serialize;
+use std::{
+    fmt,
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+
+/// # Finding Severity
+#[derive(Serialize, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Informational, not a conformance problem
+    Info,
+    /// Likely to confuse consumers of the table but not a hard violation
+    Warning,
+    /// Violates the specification
+    Error,
+}
+
+impl Severity {
+    fn name(&self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+impl FromStr for Severity {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [Severity::Info, Severity::Warning, Severity::Error]
+            .iter()
+            .find(|severity| severity.name().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, format!("unknown severity '{}'", s)))
+    }
+}
+
+/// # Validation Finding
+#[derive(Serialize, Debug)]
+pub struct Finding {
+    /// How serious the finding is
+    pub severity: Severity,
+    /// Identifier of the rule which produced the finding (e.g. "minimum-length")
+    pub rule: &'static str,
+    /// Structure type the finding concerns, if any
+    pub struct_type: Option<u8>,
+    /// Handle of the structure the finding concerns, if any
+    pub handle: Option<Handle>,
+    /// Human readable description
+    pub message: String,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:<7} [{}]", self.severity, self.rule)?;
+        if let Some(struct_type) = self.struct_type {
+            write!(f, " Type {}", struct_type)?;
+        }
+        if let Some(handle) = &self.handle {
+            write!(f, " handle {:#06X}", **handle)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// # Validation Report
+#[derive(Serialize, Debug, Default)]
+pub struct ValidationReport {
+    /// Findings in table order, table-wide findings first
+    pub findings: Vec<Finding>,
+}
+
+impl ValidationReport {
+    /// The highest severity among the findings, `None` if there are none
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.findings.iter().map(|finding| finding.severity).max()
+    }
+
+    /// Number of findings of the given severity
+    pub fn count(&self, severity: Severity) -> usize {
+        self.findings
+            .iter()
+            .filter(|finding| finding.severity == severity)
+            .count()
+    }
+
+    /// `true` if any finding is at or above the given severity
+    pub fn fails(&self, threshold: Severity) -> bool {
+        matches!(self.max_severity(), Some(severity) if severity >= threshold)
+    }
+
+    fn push(&mut self, severity: Severity, rule: &'static str, message: String) {
+        self.findings.push(Finding {
+            severity,
+            rule,
+            struct_type: None,
+            handle: None,
+            message,
+        });
+    }
+
+    fn push_struct(
+        &mut self,
+        severity: Severity,
+        rule: &'static str,
+        undefined_struct: &UndefinedStruct,
+        message: String,
+    ) {
+        self.findings.push(Finding {
+            severity,
+            rule,
+            struct_type: Some(undefined_struct.header.struct_type()),
+            handle: Some(undefined_struct.header.handle()),
+            message,
+        });
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for finding in self.findings.iter() {
+            writeln!(f, "{}", finding)?;
+        }
+        writeln!(
+            f,
+            "{} errors, {} warnings, {} info",
+            self.count(Severity::Error),
+            self.count(Severity::Warning),
+            self.count(Severity::Info)
+        )
+    }
+}
+
+/// Structure types the specification requires, with the severity of their absence
+///
+/// System Slots are only required for systems which have expansion slots.
+const REQUIRED_TYPES: [(u8, &str, Severity); 10] = [
+    (
+        SMBiosInformation::STRUCT_TYPE,
+        "BIOS Information",
+        Severity::Error,
+    ),
+    (
+        SMBiosSystemInformation::STRUCT_TYPE,
+        "System Information",
+        Severity::Error,
+    ),
+    (
+        SMBiosSystemChassisInformation::STRUCT_TYPE,
+        "System Enclosure or Chassis",
+        Severity::Error,
+    ),
+    (
+        SMBiosProcessorInformation::STRUCT_TYPE,
+        "Processor Information",
+        Severity::Error,
+    ),
+    (
+        SMBiosCacheInformation::STRUCT_TYPE,
+        "Cache Information",
+        Severity::Error,
+    ),
+    (
+        SMBiosSystemSlot::STRUCT_TYPE,
+        "System Slots",
+        Severity::Warning,
+    ),
+    (
+        SMBiosPhysicalMemoryArray::STRUCT_TYPE,
+        "Physical Memory Array",
+        Severity::Error,
+    ),
+    (
+        SMBiosMemoryDevice::STRUCT_TYPE,
+        "Memory Device",
+        Severity::Error,
+    ),
+    (
+        SMBiosMemoryArrayMappedAddress::STRUCT_TYPE,
+        "Memory Array Mapped Address",
+        Severity::Error,
+    ),
+    (
+        SMBiosSystemBootInformation::STRUCT_TYPE,
+        "System Boot Information",
+        Severity::Error,
+    ),
+];
+
+/// Structure lengths as ((major, minor), length), each introduced by the given version
+type LengthHistory = &'static [((u8, u8), u8)];
+
+/// Minimum structure lengths by type
+const MINIMUM_LENGTHS: [(u8, LengthHistory); 13] = [
+    (0, &[((2, 0), 0x12), ((2, 4), 0x18), ((3, 1), 0x1A)]),
+    (1, &[((2, 0), 0x08), ((2, 1), 0x19), ((2, 4), 0x1B)]),
+    (2, &[((2, 0), 0x08)]),
+    (3, &[((2, 0), 0x09), ((2, 1), 0x0D), ((2, 3), 0x15)]),
+    (
+        4,
+        &[
+            ((2, 0), 0x1A),
+            ((2, 3), 0x23),
+            ((2, 5), 0x28),
+            ((2, 6), 0x2A),
+            ((3, 0), 0x30),
+        ],
+    ),
+    (7, &[((2, 0), 0x0F), ((2, 1), 0x13), ((3, 1), 0x1B)]),
+    (9, &[((2, 0), 0x0C), ((2, 1), 0x0D), ((2, 6), 0x11)]),
+    (16, &[((2, 1), 0x0F), ((2, 7), 0x17)]),
+    (
+        17,
+        &[
+            ((2, 1), 0x15),
+            ((2, 3), 0x1B),
+            ((2, 6), 0x1C),
+            ((2, 7), 0x22),
+            ((2, 8), 0x28),
+            ((3, 2), 0x54),
+            ((3, 3), 0x5C),
+        ],
+    ),
+    (19, &[((2, 1), 0x0F), ((2, 7), 0x1F)]),
+    (20, &[((2, 1), 0x13), ((2, 7), 0x23)]),
+    (32, &[((2, 0), 0x0B)]),
+    (127, &[((2, 0), 0x04)]),
+];
+
+/// String fields the specification requires, as (type, offset, field name)
+const REQUIRED_STRINGS: [(u8, usize, &str); 12] = [
+    (0, 0x04, "vendor"),
+    (0, 0x05, "version"),
+    (0, 0x08, "release_date"),
+    (1, 0x04, "manufacturer"),
+    (1, 0x05, "product_name"),
+    (2, 0x04, "manufacturer"),
+    (2, 0x05, "product"),
+    (3, 0x04, "manufacturer"),
+    (4, 0x04, "socket_designation"),
+    (7, 0x04, "socket_designation"),
+    (9, 0x04, "slot_designation"),
+    (17, 0x10, "device_locator"),
+];
+
+/// Runs the conformance rule set against a table
+pub fn validate(data: &SMBiosData) -> ValidationReport {
+    let mut report = ValidationReport::default();
+
+    if data.version.is_none() {
+        report.push(
+            Severity::Info,
+            "version",
+            "the SMBIOS version is unknown; minimum lengths are checked against the earliest version"
+                .to_string(),
+        );
+    }
+
+    check_required_types(data, &mut report);
+
+    for undefined_struct in data.iter() {
+        check_minimum_length(undefined_struct, data.version, &mut report);
+        check_required_strings(undefined_struct, &mut report);
+        check_enumerations(undefined_struct, &mut report);
+    }
+
+    report
+}
+
+fn check_required_types(data: &SMBiosData, report: &mut ValidationReport) {
+    for (struct_type, name, severity) in REQUIRED_TYPES.iter() {
+        if !data
+            .iter()
+            .any(|undefined_struct| undefined_struct.header.struct_type() == *struct_type)
+        {
+            report.push(
+                *severity,
+                "required-type",
+                format!(
+                    "required structure {} (Type {}) is missing",
+                    name, struct_type
+                ),
+            );
+        }
+    }
+}
+
+fn check_minimum_length(
+    undefined_struct: &UndefinedStruct,
+    version: Option<SMBiosVersion>,
+    report: &mut ValidationReport,
+) {
+    let struct_type = undefined_struct.header.struct_type();
+    let lengths = match MINIMUM_LENGTHS.iter().find(|(t, _)| *t == struct_type) {
+        Some((_, lengths)) => lengths,
+        None => return,
+    };
+
+    let required = match version {
+        Some(version) => lengths
+            .iter()
+            .rev()
+            .find(|((major, minor), _)| SMBiosVersion::new(*major, *minor, 0) <= version),
+        None => lengths.first(),
+    };
+
+    if let Some(((major, minor), minimum)) = required {
+        let length = undefined_struct.header.length();
+        if length < *minimum {
+            report.push_struct(
+                Severity::Error,
+                "minimum-length",
+                undefined_struct,
+                format!(
+                    "length {:#04X} is less than the {:#04X} required by SMBIOS {}.{}",
+                    length, minimum, major, minor
+                ),
+            );
+        }
+    }
+}
+
+fn check_required_strings(undefined_struct: &UndefinedStruct, report: &mut ValidationReport) {
+    let struct_type = undefined_struct.header.struct_type();
+    for (_, offset, field) in REQUIRED_STRINGS
+        .iter()
+        .filter(|(t, _, _)| *t == struct_type)
+    {
+        match undefined_struct.get_field_string(*offset).as_ref() {
+            Ok(value) if value.trim().is_empty() => report.push_struct(
+                Severity::Warning,
+                "required-string",
+                undefined_struct,
+                format!("{} is empty", field),
+            ),
+            Err(SMBiosStringError::InvalidStringNumber(number)) => report.push_struct(
+                Severity::Error,
+                "required-string",
+                undefined_struct,
+                format!(
+                    "{} refers to string {} which is not in the string-set",
+                    field, number
+                ),
+            ),
+            // Fields beyond the structure are reported by the minimum length
+            // rule and invalid UTF-8 is still a non-empty string
+            _ => (),
+        }
+    }
+}
+
+fn check_enumerations(undefined_struct: &UndefinedStruct, report: &mut ValidationReport) {
+    let mut out_of_range = |field: &str, raw: String| {
+        report.push_struct(
+            Severity::Error,
+            "enumeration",
+            undefined_struct,
+            format!(
+                "{} value {} is not defined by the specification",
+                field, raw
+            ),
+        )
+    };
+
+    match undefined_struct.defined_struct() {
+        DefinedStruct::SystemChassisInformation(chassis) => {
+            if let Some(chassis_type) = chassis.chassis_type() {
+                if chassis_type.value == ChassisType::None {
+                    out_of_range("chassis_type", format!("{:#04X}", chassis_type.raw));
+                }
+            }
+        }
+        DefinedStruct::MemoryDevice(memory_device) => {
+            if let Some(memory_type) = memory_device.memory_type() {
+                if memory_type.value == MemoryDeviceType::None {
+                    out_of_range("memory_type", format!("{:#04X}", memory_type.raw));
+                }
+            }
+        }
+        DefinedStruct::ProcessorInformation(processor) => {
+            if let Some(family) = processor.processor_family() {
+                match family.value {
+                    ProcessorFamily::None => {
+                        out_of_range("processor_family", format!("{:#04X}", family.raw))
+                    }
+                    ProcessorFamily::SeeProcessorFamily2 => {
+                        if let Some(family_2) = processor.processor_family_2() {
+                            if family_2.value == ProcessorFamily::None {
+                                out_of_range(
+                                    "processor_family_2",
+                                    format!("{:#06X}", family_2.raw),
+                                );
+                            }
+                        }
+                    }
+                    _ => (),
+                }
+            }
+        }
+        _ => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_validate_test_data() {
+        let mut path = PathBuf::new();
+        path.push(".");
+        path.push("tests");
+        path.push("jeffgerlap_3_2_0");
+        path.set_extension("dat");
+
+        let data = crate::load_smbios_data_from_file(&path).unwrap();
+        let report = validate(&data);
+
+        // This table has neither System Slots nor System Boot Information
+        let missing: Vec<&str> = report
+            .findings
+            .iter()
+            .filter(|finding| finding.rule == "required-type")
+            .map(|finding| finding.message.as_str())
+            .collect();
+        assert_eq!(
+            missing,
+            vec![
+                "required structure System Slots (Type 9) is missing",
+                "required structure System Boot Information (Type 32) is missing"
+            ]
+        );
+        assert_eq!(report.max_severity(), Some(Severity::Error));
+        assert!(report.fails(Severity::Warning));
+    }
+
+    #[test]
+    fn test_validate_struct_rules() {
+        let struct_type3 = vec![
+            0x03, 0x09, 0x10, 0x00, 0x00, 0x7F, 0x05, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let data =
+            SMBiosData::from_vec_and_version(struct_type3, Some(SMBiosVersion::new(3, 0, 0)));
+        let report = validate(&data);
+
+        let messages: Vec<String> = report
+            .findings
+            .iter()
+            .filter(|finding| finding.rule != "required-type")
+            .map(|finding| format!("{}", finding))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "error   [minimum-length] Type 3 handle 0x0010: length 0x09 is less than the 0x15 required by SMBIOS 2.3",
+                "warning [required-string] Type 3 handle 0x0010: manufacturer is empty",
+                "error   [enumeration] Type 3 handle 0x0010: chassis_type value 0x7F is not defined by the specification",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_severity() {
+        assert_eq!("Warning".parse::<Severity>().unwrap(), Severity::Warning);
+        assert!("fatal".parse::<Severity>().is_err());
+        assert!(Severity::Info < Severity::Warning && Severity::Warning < Severity::Error);
+    }
+}